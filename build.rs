@@ -0,0 +1,102 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::env;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let target_features = env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+    let has_target_feature = |name: &str| target_features.split(',').any(|x| x == name);
+    let has_feature = |name: &str| env::var_os(format!("CARGO_FEATURE_{name}")).is_some();
+
+    let is_x86 = arch == "x86" || arch == "x86_64";
+    let is_unsafe = has_feature("UNSAFE");
+    let avx = has_feature("AVX");
+    let sse = has_feature("SSE");
+    let nightly_avx512 = has_feature("NIGHTLY_AVX512");
+
+    // Plain copy loops compiled with `target_feature`, they need only `unsafe`.
+    let x86_avx2 = is_x86 && is_unsafe && has_target_feature("avx2");
+    let x86_avx512bw = is_x86 && is_unsafe && nightly_avx512 && has_target_feature("avx512bw");
+    let x86_avx512f = is_x86 && is_unsafe && nightly_avx512 && has_target_feature("avx512f");
+    let aarch64_sve2 = arch == "aarch64" && is_unsafe && has_target_feature("sve2");
+
+    // SIMD kernels behind `neon`, `sse`, `avx` and `nightly_avx512` features.
+    let neon = arch == "aarch64" && is_unsafe && has_feature("NEON");
+    let avx512bw = arch == "x86_64" && x86_avx512bw;
+    let avx512f = arch == "x86_64" && x86_avx512f;
+    let avx2 = avx && x86_avx2;
+    let avx_kernel = is_x86 && is_unsafe && avx && has_target_feature("avx");
+    let ssse3 = is_x86 && is_unsafe && sse && has_target_feature("ssse3");
+    let sse2 = is_x86 && is_unsafe && sse && has_target_feature("sse2");
+
+    // Each `static_*` family is set when the kernel it picks is known at compile time,
+    // then runtime detection and boxed executors are compiled out.
+    let cfgs = [
+        ("x86_avx2", x86_avx2),
+        ("x86_avx512bw", x86_avx512bw),
+        ("x86_avx512f", x86_avx512f),
+        ("aarch64_sve2", aarch64_sve2),
+        (
+            "static_flip",
+            x86_avx512bw || (x86_avx2 && !nightly_avx512) || aarch64_sve2,
+        ),
+        ("static_flop", x86_avx512f || (x86_avx2 && !nightly_avx512)),
+        ("static_reverse", x86_avx2 || aarch64_sve2),
+        ("static_neon", neon),
+        ("static_avx512bw", avx512bw),
+        ("static_avx512f", avx512f),
+        ("static_avx2", avx2),
+        ("static_avx", avx_kernel),
+        ("static_ssse3", ssse3),
+        ("static_sse2", sse2),
+        ("static_dispatch", neon || avx2 || (ssse3 && !avx)),
+        (
+            "static_dispatch_avx512bw",
+            neon || avx512bw || (avx2 && !nightly_avx512) || (ssse3 && !avx),
+        ),
+        ("static_dispatch_ssse3", neon || ssse3),
+        (
+            "static_dispatch_f64",
+            neon || avx512f || (avx_kernel && !nightly_avx512) || (sse2 && !avx),
+        ),
+        (
+            "static_dispatch_f64x2",
+            neon || avx_kernel || (sse2 && !avx),
+        ),
+    ];
+
+    for (cfg, enabled) in cfgs {
+        println!("cargo:rustc-check-cfg=cfg({cfg})");
+        if enabled {
+            println!("cargo:rustc-cfg={cfg}");
+        }
+    }
+}
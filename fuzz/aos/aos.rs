@@ -0,0 +1,72 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::{aos_to_soa, soa_to_aos};
+use libfuzzer_sys::fuzz_target;
+
+fn check<const FIELDS: usize>(count: usize)
+where
+    [u32; FIELDS]: bytemuck::Pod,
+    [u8; FIELDS]: bytemuck::Pod,
+{
+    let src: Vec<[u32; FIELDS]> = (0..count)
+        .map(|i| core::array::from_fn(|f| (i * FIELDS + f) as u32))
+        .collect();
+    let mut soa = vec![0u32; count * FIELDS];
+    aos_to_soa::<[u32; FIELDS], u32, FIELDS>(&src, &mut soa).unwrap();
+    for (i, s) in src.iter().enumerate() {
+        for (f, &v) in s.iter().enumerate() {
+            assert_eq!(soa[f * count + i], v);
+        }
+    }
+    let mut dst = vec![[0u32; FIELDS]; count];
+    soa_to_aos::<[u32; FIELDS], u32, FIELDS>(&soa, &mut dst).unwrap();
+    assert_eq!(dst, src);
+
+    let src: Vec<[u8; FIELDS]> = src.iter().map(|s| s.map(|v| v as u8)).collect();
+    let mut soa = vec![0u8; count * FIELDS];
+    aos_to_soa::<[u8; FIELDS], u8, FIELDS>(&src, &mut soa).unwrap();
+    let mut dst = vec![[0u8; FIELDS]; count];
+    soa_to_aos::<[u8; FIELDS], u8, FIELDS>(&soa, &mut dst).unwrap();
+    assert_eq!(dst, src);
+}
+
+fuzz_target!(|data: (u8, u8)| {
+    let count = data.0 as usize;
+    match data.1 % 6 {
+        0 => check::<1>(count),
+        1 => check::<2>(count),
+        2 => check::<3>(count),
+        3 => check::<4>(count),
+        4 => check::<5>(count),
+        _ => check::<8>(count),
+    }
+});
@@ -0,0 +1,156 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::{
+    flip_arbitrary, flip_pod, flop_arbitrary, flop_pod, rotate180_arbitrary, rotate180_pod,
+    transpose_arbitrary, transpose_pod, FlipMode, FlopMode,
+};
+use libfuzzer_sys::fuzz_target;
+
+/// Checks that every element `(x, y)` of the source is moved to `position(x, y)`
+fn check<V: bytemuck::Pod>(
+    src: &[V],
+    dst: &[V],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+    position: impl Fn(usize, usize) -> (usize, usize),
+) {
+    for y in 0..height {
+        for x in 0..width {
+            let (dst_x, dst_y) = position(x, y);
+            assert_eq!(
+                bytemuck::bytes_of(&dst[dst_y * dst_stride + dst_x]),
+                bytemuck::bytes_of(&src[y * width + x])
+            );
+        }
+    }
+}
+
+fn exercise<V: bytemuck::Pod + Default>(
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) {
+    let transposed = |x: usize, y: usize| {
+        let dst_x = match flip_mode {
+            FlipMode::NoFlip => y,
+            FlipMode::Flip => height - 1 - y,
+        };
+        let dst_y = match flop_mode {
+            FlopMode::Flop => x,
+            FlopMode::NoFlop => width - 1 - x,
+        };
+        (dst_x, dst_y)
+    };
+    let flipped = |x: usize, y: usize| (width - 1 - x, y);
+    let flopped = |x: usize, y: usize| (x, height - 1 - y);
+    let rotated = |x: usize, y: usize| (width - 1 - x, height - 1 - y);
+
+    let mut src_data = vec![V::default(); width * height];
+    for (i, v) in bytemuck::cast_slice_mut::<V, u8>(&mut src_data)
+        .iter_mut()
+        .enumerate()
+    {
+        *v = (i * 31 + (i >> 8)) as u8;
+    }
+    let mut dst_data = vec![V::default(); width * height];
+    transpose_pod(
+        &src_data,
+        width,
+        &mut dst_data,
+        height,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    check(&src_data, &dst_data, height, width, height, transposed);
+    flip_pod(&src_data, width, &mut dst_data, width, width, height).unwrap();
+    check(&src_data, &dst_data, width, width, height, flipped);
+    flop_pod(&src_data, width, &mut dst_data, width, width, height).unwrap();
+    check(&src_data, &dst_data, width, width, height, flopped);
+    rotate180_pod(&src_data, width, &mut dst_data, width, width, height).unwrap();
+    check(&src_data, &dst_data, width, width, height, rotated);
+
+    transpose_arbitrary(
+        &src_data,
+        width,
+        &mut dst_data,
+        height,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    check(&src_data, &dst_data, height, width, height, transposed);
+    flip_arbitrary(&src_data, width, &mut dst_data, width, width, height).unwrap();
+    check(&src_data, &dst_data, width, width, height, flipped);
+    flop_arbitrary(&src_data, width, &mut dst_data, width, width, height).unwrap();
+    check(&src_data, &dst_data, width, width, height, flopped);
+    rotate180_arbitrary(&src_data, width, &mut dst_data, width, width, height).unwrap();
+    check(&src_data, &dst_data, width, width, height, rotated);
+}
+
+fuzz_target!(|data: (u16, u16, bool, bool)| {
+    let width = data.0 as usize;
+    let height = data.1 as usize;
+    if width > 256 || height > 256 {
+        return;
+    }
+    if width == 0 || height == 0 {
+        return;
+    }
+    let flip_mode = if data.2 {
+        FlipMode::Flip
+    } else {
+        FlipMode::NoFlip
+    };
+    let flop_mode = if data.3 {
+        FlopMode::Flop
+    } else {
+        FlopMode::NoFlop
+    };
+
+    exercise::<u8>(width, height, flip_mode, flop_mode);
+    exercise::<[u8; 2]>(width, height, flip_mode, flop_mode);
+    exercise::<[u8; 3]>(width, height, flip_mode, flop_mode);
+    exercise::<u32>(width, height, flip_mode, flop_mode);
+    exercise::<[u16; 4]>(width, height, flip_mode, flop_mode);
+    exercise::<[u8; 8]>(width, height, flip_mode, flop_mode);
+    exercise::<[u32; 4]>(width, height, flip_mode, flop_mode);
+    exercise::<[u64; 2]>(width, height, flip_mode, flop_mode);
+    exercise::<[f64; 2]>(width, height, flip_mode, flop_mode);
+    exercise::<[u8; 16]>(width, height, flip_mode, flop_mode);
+});
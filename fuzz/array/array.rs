@@ -0,0 +1,72 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::transpose_array;
+use libfuzzer_sys::fuzz_target;
+
+fn check<T: Copy + PartialEq + core::fmt::Debug + 'static, const R: usize, const C: usize>(
+    m: &[[T; C]; R],
+) {
+    let dst = transpose_array(m);
+    for y in 0..R {
+        for x in 0..C {
+            assert_eq!(dst[x][y], m[y][x]);
+        }
+    }
+}
+
+fuzz_target!(|data: [u8; 256]| {
+    let m: [[u8; 16]; 16] = core::array::from_fn(|y| core::array::from_fn(|x| data[y * 16 + x]));
+    check(&m);
+    let m: [[u8; 8]; 8] = core::array::from_fn(|y| core::array::from_fn(|x| data[y * 8 + x]));
+    check(&m);
+    let m: [[u8; 5]; 3] = core::array::from_fn(|y| core::array::from_fn(|x| data[y * 5 + x]));
+    check(&m);
+
+    let src: [u16; 128] =
+        core::array::from_fn(|i| u16::from_le_bytes([data[i * 2], data[i * 2 + 1]]));
+    let m: [[u16; 8]; 8] = core::array::from_fn(|y| core::array::from_fn(|x| src[y * 8 + x]));
+    check(&m);
+
+    let src: [u32; 64] =
+        core::array::from_fn(|i| u32::from_le_bytes(data[i * 4..i * 4 + 4].try_into().unwrap()));
+    let m: [[u32; 4]; 4] = core::array::from_fn(|y| core::array::from_fn(|x| src[y * 4 + x]));
+    check(&m);
+    let m: [[u32; 8]; 8] = core::array::from_fn(|y| core::array::from_fn(|x| src[y * 8 + x]));
+    check(&m);
+
+    let src: [u64; 32] =
+        core::array::from_fn(|i| u64::from_le_bytes(data[i * 8..i * 8 + 8].try_into().unwrap()));
+    let m: [[u64; 4]; 4] = core::array::from_fn(|y| core::array::from_fn(|x| src[y * 4 + x]));
+    check(&m);
+    let m: [[u64; 2]; 2] = core::array::from_fn(|y| core::array::from_fn(|x| src[y * 2 + x]));
+    check(&m);
+});
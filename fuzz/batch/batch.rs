@@ -0,0 +1,91 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::transpose_batch;
+use libfuzzer_sys::fuzz_target;
+
+fn check<T: Copy + PartialEq + core::fmt::Debug, const R: usize, const C: usize>(
+    src: &[[[T; C]; R]],
+    dst: &[[[T; R]; C]],
+) {
+    for (src, dst) in src.iter().zip(dst.iter()) {
+        for y in 0..R {
+            for x in 0..C {
+                assert_eq!(dst[x][y], src[y][x]);
+            }
+        }
+    }
+}
+
+/// Builds `count` matrices with distinct elements
+fn matrices<T, const R: usize, const C: usize>(
+    count: usize,
+    value: u8,
+    element: impl Fn(usize) -> T,
+) -> Vec<[[T; C]; R]> {
+    (0..count)
+        .map(|i| {
+            core::array::from_fn(|y| {
+                core::array::from_fn(|x| element(((i * R + y) * C + x) * 3 + value as usize))
+            })
+        })
+        .collect()
+}
+
+fuzz_target!(|data: (u8, u8)| {
+    let count = data.0 as usize;
+    let value = data.1;
+
+    let src = matrices::<u8, 8, 8>(count, value, |i| (i ^ (i >> 8)) as u8);
+    let mut dst = vec![[[0u8; 8]; 8]; count];
+    transpose_batch(&src, &mut dst).unwrap();
+    check(&src, &dst);
+
+    let src = matrices::<u16, 4, 4>(count, value, |i| i as u16);
+    let mut dst = vec![[[0u16; 4]; 4]; count];
+    transpose_batch(&src, &mut dst).unwrap();
+    check(&src, &dst);
+
+    let src = matrices::<f32, 8, 8>(count, value, |i| i as f32);
+    let mut dst = vec![[[0f32; 8]; 8]; count];
+    transpose_batch(&src, &mut dst).unwrap();
+    check(&src, &dst);
+
+    let src = matrices::<f64, 4, 4>(count, value, |i| i as f64);
+    let mut dst = vec![[[0f64; 4]; 4]; count];
+    transpose_batch(&src, &mut dst).unwrap();
+    check(&src, &dst);
+
+    let src = matrices::<u8, 3, 5>(count, value, |i| (i ^ (i >> 8)) as u8);
+    let mut dst = vec![[[0u8; 3]; 5]; count];
+    transpose_batch(&src, &mut dst).unwrap();
+    check(&src, &dst);
+});
@@ -0,0 +1,260 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::{
+    flip_bits, flip_packed, flop_bits, flop_packed, rotate180_bits, rotate180_packed,
+    transpose_bits, transpose_packed, BitOrder, FlipMode, FlopMode, PackedDepth,
+};
+use libfuzzer_sys::fuzz_target;
+
+/// Returns packed pixel `x` of the row
+fn get_pixel(row: &[u8], x: usize, bits: usize, lsb_first: bool) -> u8 {
+    let offset = x * bits % 8;
+    let shift = if lsb_first { offset } else { 8 - bits - offset };
+    (row[x * bits / 8] >> shift) & ((1u8 << bits) - 1)
+}
+
+fn set_pixel(row: &mut [u8], x: usize, bits: usize, lsb_first: bool, value: u8) {
+    let offset = x * bits % 8;
+    let shift = if lsb_first { offset } else { 8 - bits - offset };
+    row[x * bits / 8] |= value << shift;
+}
+
+/// Moves every pixel `(x, y)` of the source to `position(x, y)`,
+/// padding bits of the output are left cleared
+fn reference(
+    src: &[u8],
+    src_stride: usize,
+    dst_stride: usize,
+    dst_rows: usize,
+    width: usize,
+    height: usize,
+    bits: usize,
+    lsb_first: bool,
+    position: impl Fn(usize, usize) -> (usize, usize),
+) -> Vec<u8> {
+    let mut dst = vec![0u8; dst_stride * dst_rows];
+    for y in 0..height {
+        for x in 0..width {
+            let value = get_pixel(&src[y * src_stride..], x, bits, lsb_first);
+            let (dst_x, dst_y) = position(x, y);
+            set_pixel(
+                &mut dst[dst_y * dst_stride..],
+                dst_x,
+                bits,
+                lsb_first,
+                value,
+            );
+        }
+    }
+    dst
+}
+
+fuzz_target!(|data: (u16, u16, bool, bool, u8, bool)| {
+    let width = data.0 as usize;
+    let height = data.1 as usize;
+    if width > 1024 || height > 1024 {
+        return;
+    }
+    if width == 0 || height == 0 {
+        return;
+    }
+    let flip_mode = if data.2 {
+        FlipMode::Flip
+    } else {
+        FlipMode::NoFlip
+    };
+    let flop_mode = if data.3 {
+        FlopMode::Flop
+    } else {
+        FlopMode::NoFlop
+    };
+    let transposed = |x: usize, y: usize| {
+        let dst_x = match flip_mode {
+            FlipMode::NoFlip => y,
+            FlipMode::Flip => height - 1 - y,
+        };
+        let dst_y = match flop_mode {
+            FlopMode::Flop => x,
+            FlopMode::NoFlop => width - 1 - x,
+        };
+        (dst_x, dst_y)
+    };
+    let flipped = |x: usize, y: usize| (width - 1 - x, y);
+    let flopped = |x: usize, y: usize| (x, height - 1 - y);
+    let rotated = |x: usize, y: usize| (width - 1 - x, height - 1 - y);
+
+    let stride = width.div_ceil(8);
+    let transposed_stride = height.div_ceil(8);
+
+    // Padding bits of the source are set as well, they must not leak into output
+    let src_data = (0..stride * height)
+        .map(|i| (i * 37 + (i >> 8)) as u8)
+        .collect::<Vec<_>>();
+    let mut dst_data = vec![0u8; transposed_stride * width];
+    transpose_bits(
+        &src_data,
+        stride,
+        &mut dst_data,
+        transposed_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    let expected = reference(
+        &src_data,
+        stride,
+        transposed_stride,
+        width,
+        width,
+        height,
+        1,
+        false,
+        transposed,
+    );
+    assert_eq!(dst_data, expected);
+
+    let mut dst_data = vec![0u8; stride * height];
+    flip_bits(&src_data, stride, &mut dst_data, stride, width, height).unwrap();
+    let expected = reference(
+        &src_data, stride, stride, height, width, height, 1, false, flipped,
+    );
+    assert_eq!(dst_data, expected);
+    flop_bits(&src_data, stride, &mut dst_data, stride, width, height).unwrap();
+    let expected = reference(
+        &src_data, stride, stride, height, width, height, 1, false, flopped,
+    );
+    assert_eq!(dst_data, expected);
+    rotate180_bits(&src_data, stride, &mut dst_data, stride, width, height).unwrap();
+    let expected = reference(
+        &src_data, stride, stride, height, width, height, 1, false, rotated,
+    );
+    assert_eq!(dst_data, expected);
+
+    let depth = match data.4 % 3 {
+        0 => PackedDepth::One,
+        1 => PackedDepth::Two,
+        _ => PackedDepth::Four,
+    };
+    let bits = match depth {
+        PackedDepth::One => 1,
+        PackedDepth::Two => 2,
+        PackedDepth::Four => 4,
+    };
+    let bit_order = if data.5 {
+        BitOrder::LsbFirst
+    } else {
+        BitOrder::MsbFirst
+    };
+    let stride = (width * bits).div_ceil(8);
+    let transposed_stride = (height * bits).div_ceil(8);
+
+    let lsb_first = bit_order == BitOrder::LsbFirst;
+
+    let src_data = (0..stride * height)
+        .map(|i| (i * 37 + (i >> 8)) as u8)
+        .collect::<Vec<_>>();
+    let mut dst_data = vec![0u8; transposed_stride * width];
+    transpose_packed(
+        &src_data,
+        stride,
+        &mut dst_data,
+        transposed_stride,
+        width,
+        height,
+        depth,
+        bit_order,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    let expected = reference(
+        &src_data,
+        stride,
+        transposed_stride,
+        width,
+        width,
+        height,
+        bits,
+        lsb_first,
+        transposed,
+    );
+    assert_eq!(dst_data, expected);
+
+    let mut dst_data = vec![0u8; stride * height];
+    flip_packed(
+        &src_data,
+        stride,
+        &mut dst_data,
+        stride,
+        width,
+        height,
+        depth,
+        bit_order,
+    )
+    .unwrap();
+    let expected = reference(
+        &src_data, stride, stride, height, width, height, bits, lsb_first, flipped,
+    );
+    assert_eq!(dst_data, expected);
+    flop_packed(
+        &src_data,
+        stride,
+        &mut dst_data,
+        stride,
+        width,
+        height,
+        depth,
+        bit_order,
+    )
+    .unwrap();
+    let expected = reference(
+        &src_data, stride, stride, height, width, height, bits, lsb_first, flopped,
+    );
+    assert_eq!(dst_data, expected);
+    rotate180_packed(
+        &src_data,
+        stride,
+        &mut dst_data,
+        stride,
+        width,
+        height,
+        depth,
+        bit_order,
+    )
+    .unwrap();
+    let expected = reference(
+        &src_data, stride, stride, height, width, height, bits, lsb_first, rotated,
+    );
+    assert_eq!(dst_data, expected);
+});
@@ -0,0 +1,197 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::{
+    flip_rgba_f16, flop_plane_with_alpha_f16, rotate180_rgb_f16, transpose_plane_f16,
+    transpose_rgba_f16, FlipMode, FlopMode,
+};
+use half::f16;
+use libfuzzer_sys::fuzz_target;
+
+/// Checks that every pixel `(x, y)` of the source is moved to `position(x, y)` bit exact
+fn check(
+    src: &[f16],
+    src_stride: usize,
+    dst: &[f16],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+    cn: usize,
+    position: impl Fn(usize, usize) -> (usize, usize),
+) {
+    for y in 0..height {
+        for x in 0..width {
+            let (dst_x, dst_y) = position(x, y);
+            for c in 0..cn {
+                assert_eq!(
+                    dst[dst_y * dst_stride + dst_x * cn + c].to_bits(),
+                    src[y * src_stride + x * cn + c].to_bits()
+                );
+            }
+        }
+    }
+}
+
+fuzz_target!(|data: (u16, u16, bool, bool)| {
+    let width = data.0 as usize;
+    let height = data.1 as usize;
+    if width > 512 || height > 512 {
+        return;
+    }
+    if width == 0 || height == 0 {
+        return;
+    }
+    let flip_mode = if data.2 {
+        FlipMode::Flip
+    } else {
+        FlipMode::NoFlip
+    };
+    let flop_mode = if data.3 {
+        FlopMode::Flop
+    } else {
+        FlopMode::NoFlop
+    };
+
+    let transposed = |x: usize, y: usize| {
+        let dst_x = match flip_mode {
+            FlipMode::NoFlip => y,
+            FlipMode::Flip => height - 1 - y,
+        };
+        let dst_y = match flop_mode {
+            FlopMode::Flop => x,
+            FlopMode::NoFlop => width - 1 - x,
+        };
+        (dst_x, dst_y)
+    };
+
+    // Every bit pattern is used, NaN payloads included
+    let src_data = (0..width * height)
+        .map(|i| f16::from_bits(i as u16))
+        .collect::<Vec<_>>();
+    let mut dst_data = vec![f16::ZERO; width * height];
+    transpose_plane_f16(
+        &src_data,
+        width,
+        &mut dst_data,
+        height,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    check(
+        &src_data, width, &dst_data, height, width, height, 1, transposed,
+    );
+
+    let src_data = (0..width * height * 4)
+        .map(|i| f16::from_bits((i * 7) as u16))
+        .collect::<Vec<_>>();
+    let mut dst_data = vec![f16::ZERO; width * height * 4];
+    transpose_rgba_f16(
+        &src_data,
+        width * 4,
+        &mut dst_data,
+        height * 4,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    check(
+        &src_data,
+        width * 4,
+        &dst_data,
+        height * 4,
+        width,
+        height,
+        4,
+        transposed,
+    );
+    flip_rgba_f16(
+        &src_data,
+        width * 4,
+        &mut dst_data,
+        width * 4,
+        width,
+        height,
+    )
+    .unwrap();
+    check(
+        &src_data,
+        width * 4,
+        &dst_data,
+        width * 4,
+        width,
+        height,
+        4,
+        |x, y| (width - 1 - x, y),
+    );
+    rotate180_rgb_f16(
+        &src_data[..width * height * 3],
+        width * 3,
+        &mut dst_data[..width * height * 3],
+        width * 3,
+        width,
+        height,
+    )
+    .unwrap();
+    check(
+        &src_data,
+        width * 3,
+        &dst_data,
+        width * 3,
+        width,
+        height,
+        3,
+        |x, y| (width - 1 - x, height - 1 - y),
+    );
+    flop_plane_with_alpha_f16(
+        &src_data[..width * height * 2],
+        width * 2,
+        &mut dst_data[..width * height * 2],
+        width * 2,
+        width,
+        height,
+    )
+    .unwrap();
+    check(
+        &src_data,
+        width * 2,
+        &dst_data,
+        width * 2,
+        width,
+        height,
+        2,
+        |x, y| (x, height - 1 - y),
+    );
+});
@@ -0,0 +1,181 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::{
+    fftshift_2d, ifftshift_2d, roll_2d, transpose_fftshift_2d, transpose_ifftshift_2d, FlipMode,
+    FlopMode,
+};
+use libfuzzer_sys::fuzz_target;
+
+/// Moves pixel `(x, y)` to `(x + dx, y + dy)` wrapping around the edges
+fn roll<T: Copy + Default>(
+    src: &[T],
+    width: usize,
+    height: usize,
+    cn: usize,
+    dx: isize,
+    dy: isize,
+) -> Vec<T> {
+    let mut dst = vec![T::default(); src.len()];
+    for y in 0..height {
+        for x in 0..width {
+            let dst_x = (x as isize + dx).rem_euclid(width as isize) as usize;
+            let dst_y = (y as isize + dy).rem_euclid(height as isize) as usize;
+            dst[(dst_y * width + dst_x) * cn..][..cn]
+                .copy_from_slice(&src[(y * width + x) * cn..][..cn]);
+        }
+    }
+    dst
+}
+
+fn transpose<T: Copy + Default>(
+    src: &[T],
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Vec<T> {
+    let mut dst = vec![T::default(); src.len()];
+    for y in 0..height {
+        for x in 0..width {
+            let dst_x = match flip_mode {
+                FlipMode::NoFlip => y,
+                FlipMode::Flip => height - 1 - y,
+            };
+            let dst_y = match flop_mode {
+                FlopMode::Flop => x,
+                FlopMode::NoFlop => width - 1 - x,
+            };
+            dst[dst_y * height + dst_x] = src[y * width + x];
+        }
+    }
+    dst
+}
+
+fuzz_target!(|data: (u16, u16, bool, bool, i16, i16)| {
+    let width = data.0 as usize;
+    let height = data.1 as usize;
+    if width > 512 || height > 512 {
+        return;
+    }
+    let flip_mode = if data.2 {
+        FlipMode::Flip
+    } else {
+        FlipMode::NoFlip
+    };
+    let flop_mode = if data.3 {
+        FlopMode::Flop
+    } else {
+        FlopMode::NoFlop
+    };
+
+    let (half_width, half_height) = ((width / 2) as isize, (height / 2) as isize);
+    let (rest_width, rest_height) = (width as isize - half_width, height as isize - half_height);
+
+    let src_data = (0..width * height).map(|i| i as f32).collect::<Vec<_>>();
+    let mut dst_data = vec![0f32; width * height];
+    fftshift_2d(&src_data, width, &mut dst_data, width, width, height).unwrap();
+    assert_eq!(
+        dst_data,
+        roll(&src_data, width, height, 1, half_width, half_height)
+    );
+    ifftshift_2d(&src_data, width, &mut dst_data, width, width, height).unwrap();
+    assert_eq!(
+        dst_data,
+        roll(&src_data, width, height, 1, rest_width, rest_height)
+    );
+
+    let src_data = (0..width * height * 3)
+        .map(|i| (i ^ (i >> 8)) as u8)
+        .collect::<Vec<_>>();
+    let mut dst_data = vec![0u8; width * height * 3];
+    roll_2d::<u8, 3>(
+        &src_data,
+        width * 3,
+        &mut dst_data,
+        width * 3,
+        width,
+        height,
+        data.4 as isize,
+        data.5 as isize,
+    )
+    .unwrap();
+    assert_eq!(
+        dst_data,
+        roll(
+            &src_data,
+            width,
+            height,
+            3,
+            data.4 as isize,
+            data.5 as isize
+        )
+    );
+
+    // Shifts of transposed output use its dimensions, `height` columns and `width` rows
+    let src_data = (0..width * height).map(|i| i as f32).collect::<Vec<_>>();
+    let mut dst_data = vec![0f32; width * height];
+    transpose_fftshift_2d(
+        &src_data,
+        width,
+        &mut dst_data,
+        height,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    let transposed = transpose(&src_data, width, height, flip_mode, flop_mode);
+    assert_eq!(
+        dst_data,
+        roll(&transposed, height, width, 1, half_height, half_width)
+    );
+
+    let src_data = (0..width * height).map(|i| i as f64).collect::<Vec<_>>();
+    let mut dst_data = vec![0f64; width * height];
+    transpose_ifftshift_2d(
+        &src_data,
+        width,
+        &mut dst_data,
+        height,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    let transposed = transpose(&src_data, width, height, flip_mode, flop_mode);
+    assert_eq!(
+        dst_data,
+        roll(&transposed, height, width, 1, rest_height, rest_width)
+    );
+});
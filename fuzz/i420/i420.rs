@@ -0,0 +1,258 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::{rotate_i420, rotate_i420_with_siting, ChromaSiting, Orientation};
+use libfuzzer_sys::fuzz_target;
+
+/// Returns position of source sample `(x, y)` in the oriented frame
+fn oriented_position(
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+) -> (usize, usize) {
+    match orientation {
+        Orientation::Identity => (x, y),
+        Orientation::Rotate90 => (height - 1 - y, x),
+        Orientation::Rotate180 => (width - 1 - x, height - 1 - y),
+        Orientation::Rotate270 => (y, width - 1 - x),
+        Orientation::Flip => (width - 1 - x, y),
+        Orientation::Flop => (x, height - 1 - y),
+        Orientation::Transpose => (y, x),
+        Orientation::Transverse => (height - 1 - y, width - 1 - x),
+    }
+}
+
+fn orient(src: &[u8], width: usize, height: usize, orientation: Orientation) -> Vec<u8> {
+    let (dst_width, _) = orientation.oriented_size(width, height);
+    let mut dst = vec![0u8; width * height];
+    for y in 0..height {
+        for x in 0..width {
+            let (dst_x, dst_y) = oriented_position(x, y, width, height, orientation);
+            dst[dst_y * dst_width + dst_x] = src[y * width + x];
+        }
+    }
+    dst
+}
+
+/// Returns position in quarters of source chroma sample along the source axis
+/// of destination chroma sample `index`, offsets are in halves of luma sample.
+fn source_position(
+    index: usize,
+    luma_length: usize,
+    source_offset: usize,
+    target_offset: usize,
+    mirrored: bool,
+) -> isize {
+    let position = 4 * index as isize + target_offset as isize;
+    let position = if mirrored {
+        2 * (luma_length as isize - 1) - position
+    } else {
+        position
+    };
+    position - source_offset as isize
+}
+
+fn taps(position: isize, length: usize) -> (usize, usize, u16) {
+    let last = length as isize - 1;
+    let index = position.div_euclid(4);
+    (
+        index.clamp(0, last) as usize,
+        (index + 1).clamp(0, last) as usize,
+        position.rem_euclid(4) as u16,
+    )
+}
+
+/// Orients chroma plane keeping its siting with bilinear filter
+fn orient_sited(
+    src: &[u8],
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+    siting: ChromaSiting,
+) -> Vec<u8> {
+    let chroma_width = width.div_ceil(2);
+    let chroma_height = height.div_ceil(2);
+    let (offset_x, offset_y) = match siting {
+        ChromaSiting::Center => (1, 1),
+        ChromaSiting::Left => (0, 1),
+        ChromaSiting::TopLeft => (0, 0),
+    };
+    // Destination axes mapped to source axes, whether it is read backwards
+    let (mirrored_x, mirrored_y) = match orientation {
+        Orientation::Identity | Orientation::Transpose => (false, false),
+        Orientation::Rotate90 | Orientation::Flip => (true, false),
+        Orientation::Rotate270 | Orientation::Flop => (false, true),
+        Orientation::Rotate180 | Orientation::Transverse => (true, true),
+    };
+    let swaps = orientation.swaps_dimensions();
+    let (luma_x, luma_y) = if swaps {
+        (height, width)
+    } else {
+        (width, height)
+    };
+    let (source_x, source_y) = if swaps {
+        (offset_y, offset_x)
+    } else {
+        (offset_x, offset_y)
+    };
+    let (length_x, length_y) = if swaps {
+        (chroma_height, chroma_width)
+    } else {
+        (chroma_width, chroma_height)
+    };
+    let sample = |a: usize, b: usize| {
+        if swaps {
+            src[a * chroma_width + b] as u16
+        } else {
+            src[b * chroma_width + a] as u16
+        }
+    };
+
+    let mut dst = vec![0u8; chroma_width * chroma_height];
+    for y in 0..length_y {
+        let (y0, y1, wy) = taps(
+            source_position(y, luma_y, source_y, offset_y, mirrored_y),
+            length_y,
+        );
+        for x in 0..length_x {
+            let (x0, x1, wx) = taps(
+                source_position(x, luma_x, source_x, offset_x, mirrored_x),
+                length_x,
+            );
+            let top = sample(x0, y0) * (4 - wx) + sample(x1, y0) * wx;
+            let bottom = sample(x0, y1) * (4 - wx) + sample(x1, y1) * wx;
+            dst[y * length_x + x] = ((top * (4 - wy) + bottom * wy + 8) >> 4) as u8;
+        }
+    }
+    dst
+}
+
+fuzz_target!(|data: (u16, u16, u8, u8)| {
+    let width = data.0 as usize;
+    let height = data.1 as usize;
+    if width > 512 || height > 512 {
+        return;
+    }
+    if width == 0 || height == 0 {
+        return;
+    }
+    let orientation = match data.2 % 8 {
+        0 => Orientation::Identity,
+        1 => Orientation::Rotate90,
+        2 => Orientation::Rotate180,
+        3 => Orientation::Rotate270,
+        4 => Orientation::Flip,
+        5 => Orientation::Flop,
+        6 => Orientation::Transpose,
+        _ => Orientation::Transverse,
+    };
+    let siting = match data.3 % 3 {
+        0 => ChromaSiting::Center,
+        1 => ChromaSiting::Left,
+        _ => ChromaSiting::TopLeft,
+    };
+    let chroma_width = width.div_ceil(2);
+    let chroma_height = height.div_ceil(2);
+    let (dst_width, dst_height) = orientation.oriented_size(width, height);
+    let (dst_chroma_width, dst_chroma_height) =
+        orientation.oriented_size(chroma_width, chroma_height);
+
+    let src_y = (0..width * height)
+        .map(|i| (i ^ (i >> 8)) as u8)
+        .collect::<Vec<_>>();
+    let src_u = (0..chroma_width * chroma_height)
+        .map(|i| (i * 3 + (i >> 8)) as u8)
+        .collect::<Vec<_>>();
+    let src_v = (0..chroma_width * chroma_height)
+        .map(|i| (i * 5 + (i >> 8)) as u8)
+        .collect::<Vec<_>>();
+    let mut dst_y = vec![0u8; dst_width * dst_height];
+    let mut dst_u = vec![0u8; dst_chroma_width * dst_chroma_height];
+    let mut dst_v = vec![0u8; dst_chroma_width * dst_chroma_height];
+    rotate_i420(
+        &src_y,
+        width,
+        &src_u,
+        chroma_width,
+        &src_v,
+        chroma_width,
+        &mut dst_y,
+        dst_width,
+        &mut dst_u,
+        dst_chroma_width,
+        &mut dst_v,
+        dst_chroma_width,
+        width,
+        height,
+        orientation,
+    )
+    .unwrap();
+    assert_eq!(dst_y, orient(&src_y, width, height, orientation));
+    assert_eq!(
+        dst_u,
+        orient(&src_u, chroma_width, chroma_height, orientation)
+    );
+    assert_eq!(
+        dst_v,
+        orient(&src_v, chroma_width, chroma_height, orientation)
+    );
+
+    rotate_i420_with_siting(
+        &src_y,
+        width,
+        &src_u,
+        chroma_width,
+        &src_v,
+        chroma_width,
+        &mut dst_y,
+        dst_width,
+        &mut dst_u,
+        dst_chroma_width,
+        &mut dst_v,
+        dst_chroma_width,
+        width,
+        height,
+        orientation,
+        siting,
+    )
+    .unwrap();
+    assert_eq!(dst_y, orient(&src_y, width, height, orientation));
+    assert_eq!(
+        dst_u,
+        orient_sited(&src_u, width, height, orientation, siting)
+    );
+    assert_eq!(
+        dst_v,
+        orient_sited(&src_v, width, height, orientation, siting)
+    );
+});
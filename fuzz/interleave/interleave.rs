@@ -0,0 +1,251 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::{
+    deinterleave_2ch, deinterleave_2ch16, deinterleave_2ch_f32, deinterleave_3ch,
+    deinterleave_3ch16, deinterleave_3ch_f32, deinterleave_4ch, deinterleave_4ch16,
+    deinterleave_4ch_f32, interleave_2ch, interleave_2ch16, interleave_2ch_f32, interleave_3ch,
+    interleave_3ch16, interleave_3ch_f32, interleave_4ch, interleave_4ch16, interleave_4ch_f32,
+    transpose_deinterleave_2ch, transpose_deinterleave_2ch16, transpose_deinterleave_2ch_f32,
+    transpose_deinterleave_3ch, transpose_deinterleave_3ch16, transpose_deinterleave_3ch_f32,
+    transpose_deinterleave_4ch, transpose_deinterleave_4ch16, transpose_deinterleave_4ch_f32,
+    FlipMode, FlopMode, TransposeError,
+};
+use libfuzzer_sys::fuzz_target;
+
+type Deinterleave<T, const CN: usize> =
+    fn(&[T], usize, [&mut [T]; CN], [usize; CN], usize, usize) -> Result<(), TransposeError>;
+type Interleave<T, const CN: usize> =
+    fn([&[T]; CN], [usize; CN], &mut [T], usize, usize, usize) -> Result<(), TransposeError>;
+type TransposeDeinterleave<T, const CN: usize> = fn(
+    &[T],
+    usize,
+    [&mut [T]; CN],
+    [usize; CN],
+    usize,
+    usize,
+    FlipMode,
+    FlopMode,
+) -> Result<(), TransposeError>;
+
+/// Checks that channel `c` of pixel `(x, y)` is stored in plane `c` at `position(x, y)`
+fn check<T: Copy + PartialEq + core::fmt::Debug, const CN: usize>(
+    src: &[T],
+    planes: &[Vec<T>; CN],
+    stride: usize,
+    width: usize,
+    height: usize,
+    position: impl Fn(usize, usize) -> (usize, usize),
+) {
+    for y in 0..height {
+        for x in 0..width {
+            let (dst_x, dst_y) = position(x, y);
+            for (c, plane) in planes.iter().enumerate() {
+                assert_eq!(plane[dst_y * stride + dst_x], src[(y * width + x) * CN + c]);
+            }
+        }
+    }
+}
+
+fn exercise<T: Copy + Default + PartialEq + core::fmt::Debug, const CN: usize>(
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+    sample: impl Fn(usize) -> T,
+    deinterleave: Deinterleave<T, CN>,
+    interleave: Interleave<T, CN>,
+    transpose_deinterleave: TransposeDeinterleave<T, CN>,
+) {
+    let src_data = (0..width * height * CN).map(sample).collect::<Vec<_>>();
+    let mut planes: [Vec<T>; CN] = core::array::from_fn(|_| vec![T::default(); width * height]);
+
+    deinterleave(
+        &src_data,
+        width * CN,
+        planes.each_mut().map(|x| x.as_mut_slice()),
+        [width; CN],
+        width,
+        height,
+    )
+    .unwrap();
+    check(&src_data, &planes, width, width, height, |x, y| (x, y));
+
+    let mut dst_data = vec![T::default(); width * height * CN];
+    interleave(
+        planes.each_ref().map(|x| x.as_slice()),
+        [width; CN],
+        &mut dst_data,
+        width * CN,
+        width,
+        height,
+    )
+    .unwrap();
+    assert_eq!(dst_data, src_data);
+
+    transpose_deinterleave(
+        &src_data,
+        width * CN,
+        planes.each_mut().map(|x| x.as_mut_slice()),
+        [height; CN],
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    check(&src_data, &planes, height, width, height, |x, y| {
+        let dst_x = match flip_mode {
+            FlipMode::NoFlip => y,
+            FlipMode::Flip => height - 1 - y,
+        };
+        let dst_y = match flop_mode {
+            FlopMode::Flop => x,
+            FlopMode::NoFlop => width - 1 - x,
+        };
+        (dst_x, dst_y)
+    });
+}
+
+fuzz_target!(|data: (u16, u16, bool, bool)| {
+    let width = data.0 as usize;
+    let height = data.1 as usize;
+    // Tall images are allowed to reach banding by rows in transposition
+    if width > 512 || height > 16384 || width * height > 512 * 512 {
+        return;
+    }
+    let flip_mode = if data.2 {
+        FlipMode::Flip
+    } else {
+        FlipMode::NoFlip
+    };
+    let flop_mode = if data.3 {
+        FlopMode::Flop
+    } else {
+        FlopMode::NoFlop
+    };
+
+    let u8_sample = |i: usize| (i ^ (i >> 8)) as u8;
+    exercise::<u8, 2>(
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        u8_sample,
+        deinterleave_2ch,
+        interleave_2ch,
+        transpose_deinterleave_2ch,
+    );
+    exercise::<u8, 3>(
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        u8_sample,
+        deinterleave_3ch,
+        interleave_3ch,
+        transpose_deinterleave_3ch,
+    );
+    exercise::<u8, 4>(
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        u8_sample,
+        deinterleave_4ch,
+        interleave_4ch,
+        transpose_deinterleave_4ch,
+    );
+
+    let u16_sample = |i: usize| i as u16;
+    exercise::<u16, 2>(
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        u16_sample,
+        deinterleave_2ch16,
+        interleave_2ch16,
+        transpose_deinterleave_2ch16,
+    );
+    exercise::<u16, 3>(
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        u16_sample,
+        deinterleave_3ch16,
+        interleave_3ch16,
+        transpose_deinterleave_3ch16,
+    );
+    exercise::<u16, 4>(
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        u16_sample,
+        deinterleave_4ch16,
+        interleave_4ch16,
+        transpose_deinterleave_4ch16,
+    );
+
+    let f32_sample = |i: usize| i as f32;
+    exercise::<f32, 2>(
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        f32_sample,
+        deinterleave_2ch_f32,
+        interleave_2ch_f32,
+        transpose_deinterleave_2ch_f32,
+    );
+    exercise::<f32, 3>(
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        f32_sample,
+        deinterleave_3ch_f32,
+        interleave_3ch_f32,
+        transpose_deinterleave_3ch_f32,
+    );
+    exercise::<f32, 4>(
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        f32_sample,
+        deinterleave_4ch_f32,
+        interleave_4ch_f32,
+        transpose_deinterleave_4ch_f32,
+    );
+});
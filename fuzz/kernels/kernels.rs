@@ -0,0 +1,74 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::kernels::{
+    transpose_16x16_u8, transpose_4x4_f64, transpose_8x8_u16, transpose_8x8_u32,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: [u8; 256]| {
+    let dst = transpose_16x16_u8(&data);
+    for y in 0..16 {
+        for x in 0..16 {
+            assert_eq!(dst[x * 16 + y], data[y * 16 + x]);
+        }
+    }
+
+    let src: [u16; 64] =
+        core::array::from_fn(|i| u16::from_le_bytes([data[i * 2], data[i * 2 + 1]]));
+    let dst = transpose_8x8_u16(&src);
+    for y in 0..8 {
+        for x in 0..8 {
+            assert_eq!(dst[x * 8 + y], src[y * 8 + x]);
+        }
+    }
+
+    let src: [u32; 64] =
+        core::array::from_fn(|i| u32::from_le_bytes(data[i * 4..i * 4 + 4].try_into().unwrap()));
+    let dst = transpose_8x8_u32(&src);
+    for y in 0..8 {
+        for x in 0..8 {
+            assert_eq!(dst[x * 8 + y], src[y * 8 + x]);
+        }
+    }
+
+    let src: [f64; 16] = core::array::from_fn(|i| {
+        f64::from_bits(u64::from_le_bytes(
+            data[i * 8..i * 8 + 8].try_into().unwrap(),
+        ))
+    });
+    let dst = transpose_4x4_f64(&src);
+    for y in 0..4 {
+        for x in 0..4 {
+            assert_eq!(dst[x * 4 + y].to_bits(), src[y * 4 + x].to_bits());
+        }
+    }
+});
@@ -0,0 +1,162 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::{rotate_nv12, rotate_nv12_to_i420, Orientation};
+use libfuzzer_sys::fuzz_target;
+
+/// Returns position of source sample `(x, y)` in the oriented frame
+fn oriented_position(
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+) -> (usize, usize) {
+    match orientation {
+        Orientation::Identity => (x, y),
+        Orientation::Rotate90 => (height - 1 - y, x),
+        Orientation::Rotate180 => (width - 1 - x, height - 1 - y),
+        Orientation::Rotate270 => (y, width - 1 - x),
+        Orientation::Flip => (width - 1 - x, y),
+        Orientation::Flop => (x, height - 1 - y),
+        Orientation::Transpose => (y, x),
+        Orientation::Transverse => (height - 1 - y, width - 1 - x),
+    }
+}
+
+fn orient<T: Copy + Default>(
+    src: &[T],
+    width: usize,
+    height: usize,
+    cn: usize,
+    orientation: Orientation,
+) -> Vec<T> {
+    let (dst_width, _) = orientation.oriented_size(width, height);
+    let mut dst = vec![T::default(); width * height * cn];
+    for y in 0..height {
+        for x in 0..width {
+            let (dst_x, dst_y) = oriented_position(x, y, width, height, orientation);
+            let dst = &mut dst[(dst_y * dst_width + dst_x) * cn..][..cn];
+            dst.copy_from_slice(&src[(y * width + x) * cn..][..cn]);
+        }
+    }
+    dst
+}
+
+fuzz_target!(|data: (u16, u16, u8)| {
+    let width = data.0 as usize;
+    let height = data.1 as usize;
+    if width > 512 || height > 512 {
+        return;
+    }
+    if width == 0 || height == 0 {
+        return;
+    }
+    let orientation = match data.2 % 8 {
+        0 => Orientation::Identity,
+        1 => Orientation::Rotate90,
+        2 => Orientation::Rotate180,
+        3 => Orientation::Rotate270,
+        4 => Orientation::Flip,
+        5 => Orientation::Flop,
+        6 => Orientation::Transpose,
+        _ => Orientation::Transverse,
+    };
+    let chroma_width = width.div_ceil(2);
+    let chroma_height = height.div_ceil(2);
+    let (dst_width, dst_height) = orientation.oriented_size(width, height);
+    let (dst_chroma_width, dst_chroma_height) =
+        orientation.oriented_size(chroma_width, chroma_height);
+
+    let src_y = (0..width * height)
+        .map(|i| (i ^ (i >> 8)) as u8)
+        .collect::<Vec<_>>();
+    let src_uv = (0..chroma_width * 2 * chroma_height)
+        .map(|i| (i * 3 + (i >> 8)) as u8)
+        .collect::<Vec<_>>();
+    let mut dst_y = vec![0u8; dst_width * dst_height];
+    let mut dst_uv = vec![0u8; dst_chroma_width * 2 * dst_chroma_height];
+    rotate_nv12(
+        &src_y,
+        width,
+        &src_uv,
+        chroma_width * 2,
+        &mut dst_y,
+        dst_width,
+        &mut dst_uv,
+        dst_chroma_width * 2,
+        width,
+        height,
+        orientation,
+    )
+    .unwrap();
+    assert_eq!(dst_y, orient(&src_y, width, height, 1, orientation));
+    assert_eq!(
+        dst_uv,
+        orient(&src_uv, chroma_width, chroma_height, 2, orientation)
+    );
+
+    dst_y.fill(0);
+    let mut dst_u = vec![0u8; dst_chroma_width * dst_chroma_height];
+    let mut dst_v = vec![0u8; dst_chroma_width * dst_chroma_height];
+    rotate_nv12_to_i420(
+        &src_y,
+        width,
+        &src_uv,
+        chroma_width * 2,
+        &mut dst_y,
+        dst_width,
+        &mut dst_u,
+        dst_chroma_width,
+        &mut dst_v,
+        dst_chroma_width,
+        width,
+        height,
+        orientation,
+    )
+    .unwrap();
+    assert_eq!(dst_y, orient(&src_y, width, height, 1, orientation));
+    let src_u = src_uv.iter().copied().step_by(2).collect::<Vec<_>>();
+    let src_v = src_uv
+        .iter()
+        .copied()
+        .skip(1)
+        .step_by(2)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        dst_u,
+        orient(&src_u, chroma_width, chroma_height, 1, orientation)
+    );
+    assert_eq!(
+        dst_v,
+        orient(&src_v, chroma_width, chroma_height, 1, orientation)
+    );
+});
@@ -0,0 +1,195 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::{
+    transpose_plane_f32_par, transpose_plane_par, transpose_rgb_par, transpose_rgba16_par,
+    FlipMode, FlopMode,
+};
+use libfuzzer_sys::fuzz_target;
+
+fn check<T: Copy + PartialEq + core::fmt::Debug>(
+    src: &[T],
+    src_stride: usize,
+    dst: &[T],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+    cn: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) {
+    for y in 0..height {
+        for x in 0..width {
+            let dst_y = match flop_mode {
+                FlopMode::Flop => x,
+                FlopMode::NoFlop => width - 1 - x,
+            };
+            let dst_x = match flip_mode {
+                FlipMode::NoFlip => y,
+                FlipMode::Flip => height - 1 - y,
+            };
+            assert_eq!(
+                dst[dst_y * dst_stride + dst_x * cn..][..cn],
+                src[y * src_stride + x * cn..][..cn]
+            );
+        }
+    }
+}
+
+fuzz_target!(|data: (u16, u16, u8, bool, bool)| {
+    let width = data.0 as usize;
+    let height = data.1 as usize;
+    if width > 2048 || height > 2048 {
+        return;
+    }
+    if width == 0 || height == 0 {
+        return;
+    }
+    let padding = data.2 as usize % 4;
+    let flip_mode = if data.3 {
+        FlipMode::Flip
+    } else {
+        FlipMode::NoFlip
+    };
+    let flop_mode = if data.4 {
+        FlopMode::Flop
+    } else {
+        FlopMode::NoFlop
+    };
+
+    let src_data = (0..(width + padding) * height)
+        .map(|i| (i ^ (i >> 8)) as u8)
+        .collect::<Vec<_>>();
+    let mut dst_data = vec![0u8; (height + padding) * width];
+    transpose_plane_par(
+        &src_data,
+        width + padding,
+        &mut dst_data,
+        height + padding,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    check(
+        &src_data,
+        width + padding,
+        &dst_data,
+        height + padding,
+        width,
+        height,
+        1,
+        flip_mode,
+        flop_mode,
+    );
+
+    let src_data = (0..(width + padding) * 3 * height)
+        .map(|i| (i ^ (i >> 8)) as u8)
+        .collect::<Vec<_>>();
+    let mut dst_data = vec![0u8; (height + padding) * 3 * width];
+    transpose_rgb_par(
+        &src_data,
+        (width + padding) * 3,
+        &mut dst_data,
+        (height + padding) * 3,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    check(
+        &src_data,
+        (width + padding) * 3,
+        &dst_data,
+        (height + padding) * 3,
+        width,
+        height,
+        3,
+        flip_mode,
+        flop_mode,
+    );
+
+    let src_data = (0..(width + padding) * 4 * height)
+        .map(|i| i as u16)
+        .collect::<Vec<_>>();
+    let mut dst_data = vec![0u16; (height + padding) * 4 * width];
+    transpose_rgba16_par(
+        &src_data,
+        (width + padding) * 4,
+        &mut dst_data,
+        (height + padding) * 4,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    check(
+        &src_data,
+        (width + padding) * 4,
+        &dst_data,
+        (height + padding) * 4,
+        width,
+        height,
+        4,
+        flip_mode,
+        flop_mode,
+    );
+
+    let src_data = (0..(width + padding) * height)
+        .map(|i| i as f32)
+        .collect::<Vec<_>>();
+    let mut dst_data = vec![0f32; (height + padding) * width];
+    transpose_plane_f32_par(
+        &src_data,
+        width + padding,
+        &mut dst_data,
+        height + padding,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    check(
+        &src_data,
+        width + padding,
+        &dst_data,
+        height + padding,
+        width,
+        height,
+        1,
+        flip_mode,
+        flop_mode,
+    );
+});
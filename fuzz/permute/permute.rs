@@ -0,0 +1,112 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::permute_axes;
+use libfuzzer_sys::fuzz_target;
+
+/// Reads source element for every destination index in row major order
+fn reference<T: Copy>(
+    src: &[T],
+    shape: &[usize; 4],
+    strides: &[usize; 4],
+    perm: &[usize; 4],
+) -> Vec<T> {
+    let dst_shape = perm.map(|axis| shape[axis]);
+    let mut dst = Vec::new();
+    for a in 0..dst_shape[0] {
+        for b in 0..dst_shape[1] {
+            for c in 0..dst_shape[2] {
+                for d in 0..dst_shape[3] {
+                    let offset = [a, b, c, d]
+                        .iter()
+                        .zip(perm.iter())
+                        .map(|(&index, &axis)| index * strides[axis])
+                        .sum::<usize>();
+                    dst.push(src[offset]);
+                }
+            }
+        }
+    }
+    dst
+}
+
+fuzz_target!(|data: (u8, u8, u8, u8, u8, u8)| {
+    let shape = [
+        data.0 as usize % 64,
+        data.1 as usize % 64,
+        data.2 as usize % 64,
+        data.3 as usize % 8,
+    ];
+    let perms = [
+        [0usize, 1, 2, 3],
+        [0, 3, 1, 2],
+        [0, 2, 3, 1],
+        [3, 2, 1, 0],
+        [1, 0, 3, 2],
+        [2, 0, 3, 1],
+    ];
+    let perm = perms[data.4 as usize % perms.len()];
+    let padding = data.5 as usize % 4;
+    let strides = [
+        shape[1] * shape[2] * shape[3] + padding,
+        shape[2] * shape[3],
+        shape[3],
+        1,
+    ];
+    let len = if shape.iter().any(|&x| x == 0) {
+        0
+    } else {
+        strides
+            .iter()
+            .zip(shape.iter())
+            .map(|(s, d)| s * (d - 1))
+            .sum::<usize>()
+            + 1
+    };
+    let total = shape.iter().product::<usize>();
+
+    let src_data = (0..len).map(|i| (i ^ (i >> 8)) as u8).collect::<Vec<_>>();
+    let mut dst_data = vec![0u8; total];
+    permute_axes(&src_data, &shape, &strides, &perm, &mut dst_data).unwrap();
+    assert_eq!(dst_data, reference(&src_data, &shape, &strides, &perm));
+
+    let src_data = (0..len).map(|i| i as f32).collect::<Vec<_>>();
+    let mut dst_data = vec![0f32; total];
+    permute_axes(&src_data, &shape, &strides, &perm, &mut dst_data).unwrap();
+    assert_eq!(dst_data, reference(&src_data, &shape, &strides, &perm));
+
+    let src_data = (0..len)
+        .map(|i| [i as u8, (i >> 8) as u8, (i >> 16) as u8])
+        .collect::<Vec<_>>();
+    let mut dst_data = vec![[0u8; 3]; total];
+    permute_axes(&src_data, &shape, &strides, &perm, &mut dst_data).unwrap();
+    assert_eq!(dst_data, reference(&src_data, &shape, &strides, &perm));
+});
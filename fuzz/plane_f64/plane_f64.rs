@@ -0,0 +1,118 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::{
+    flip_plane_f64, flop_plane_f64, rotate180_plane_f64, transpose_plane_f64, FlipMode, FlopMode,
+};
+use libfuzzer_sys::fuzz_target;
+
+/// Checks that every sample `(x, y)` of the source is moved to `position(x, y)` bit exact
+fn check(
+    src: &[f64],
+    dst: &[f64],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+    position: impl Fn(usize, usize) -> (usize, usize),
+) {
+    for y in 0..height {
+        for x in 0..width {
+            let (dst_x, dst_y) = position(x, y);
+            assert_eq!(
+                dst[dst_y * dst_stride + dst_x].to_bits(),
+                src[y * width + x].to_bits()
+            );
+        }
+    }
+}
+
+fuzz_target!(|data: (u16, u16, bool, bool)| {
+    let width = data.0 as usize;
+    let height = data.1 as usize;
+    if width > 512 || height > 512 {
+        return;
+    }
+    if width == 0 || height == 0 {
+        return;
+    }
+    let flip_mode = if data.2 {
+        FlipMode::Flip
+    } else {
+        FlipMode::NoFlip
+    };
+    let flop_mode = if data.3 {
+        FlopMode::Flop
+    } else {
+        FlopMode::NoFlop
+    };
+
+    let transposed = |x: usize, y: usize| {
+        let dst_x = match flip_mode {
+            FlipMode::NoFlip => y,
+            FlipMode::Flip => height - 1 - y,
+        };
+        let dst_y = match flop_mode {
+            FlopMode::Flop => x,
+            FlopMode::NoFlop => width - 1 - x,
+        };
+        (dst_x, dst_y)
+    };
+
+    let src_data = (0..width * height)
+        .map(|i| f64::from_bits((i as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15)))
+        .collect::<Vec<_>>();
+    let mut dst_data = vec![0f64; width * height];
+    transpose_plane_f64(
+        &src_data,
+        width,
+        &mut dst_data,
+        height,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+    .unwrap();
+    check(&src_data, &dst_data, height, width, height, transposed);
+
+    flip_plane_f64(&src_data, width, &mut dst_data, width, width, height).unwrap();
+    check(&src_data, &dst_data, width, width, height, |x, y| {
+        (width - 1 - x, y)
+    });
+    flop_plane_f64(&src_data, width, &mut dst_data, width, width, height).unwrap();
+    check(&src_data, &dst_data, width, width, height, |x, y| {
+        (x, height - 1 - y)
+    });
+    rotate180_plane_f64(&src_data, width, &mut dst_data, width, width, height).unwrap();
+    check(&src_data, &dst_data, width, width, height, |x, y| {
+        (width - 1 - x, height - 1 - y)
+    });
+});
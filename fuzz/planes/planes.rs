@@ -0,0 +1,151 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::{orient_planes, orient_planes_f32, Orientation};
+use libfuzzer_sys::fuzz_target;
+
+/// Returns position of source sample `(x, y)` in the oriented frame
+fn oriented_position(
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+) -> (usize, usize) {
+    match orientation {
+        Orientation::Identity => (x, y),
+        Orientation::Rotate90 => (height - 1 - y, x),
+        Orientation::Rotate180 => (width - 1 - x, height - 1 - y),
+        Orientation::Rotate270 => (y, width - 1 - x),
+        Orientation::Flip => (width - 1 - x, y),
+        Orientation::Flop => (x, height - 1 - y),
+        Orientation::Transpose => (y, x),
+        Orientation::Transverse => (height - 1 - y, width - 1 - x),
+    }
+}
+
+fn orient<T: Copy + Default>(
+    src: &[T],
+    width: usize,
+    height: usize,
+    cn: usize,
+    orientation: Orientation,
+) -> Vec<T> {
+    let (dst_width, _) = orientation.oriented_size(width, height);
+    let mut dst = vec![T::default(); width * height * cn];
+    for y in 0..height {
+        for x in 0..width {
+            let (dst_x, dst_y) = oriented_position(x, y, width, height, orientation);
+            let dst = &mut dst[(dst_y * dst_width + dst_x) * cn..][..cn];
+            dst.copy_from_slice(&src[(y * width + x) * cn..][..cn]);
+        }
+    }
+    dst
+}
+
+fuzz_target!(|data: (u16, u16, u8, u8)| {
+    let width = data.0 as usize;
+    let height = data.1 as usize;
+    if width > 512 || height > 512 {
+        return;
+    }
+    if width == 0 || height == 0 {
+        return;
+    }
+    let orientation = match data.2 % 8 {
+        0 => Orientation::Identity,
+        1 => Orientation::Rotate90,
+        2 => Orientation::Rotate180,
+        3 => Orientation::Rotate270,
+        4 => Orientation::Flip,
+        5 => Orientation::Flop,
+        6 => Orientation::Transpose,
+        _ => Orientation::Transverse,
+    };
+    let planes = (data.3 % 6) as usize + 1;
+    let (dst_width, dst_height) = orientation.oriented_size(width, height);
+    let src_strides = vec![width; planes];
+    let dst_strides = vec![dst_width; planes];
+
+    let src_data = (0..planes)
+        .map(|p| {
+            (0..width * height)
+                .map(|i| (i * (p * 2 + 1) + (i >> 8)) as u8)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut dst_data = vec![vec![0u8; dst_width * dst_height]; planes];
+    let src = src_data.iter().map(|x| x.as_slice()).collect::<Vec<_>>();
+    let mut dst = dst_data
+        .iter_mut()
+        .map(|x| x.as_mut_slice())
+        .collect::<Vec<_>>();
+    orient_planes(
+        &src,
+        &src_strides,
+        &mut dst,
+        &dst_strides,
+        width,
+        height,
+        orientation,
+    )
+    .unwrap();
+    for (src, dst) in src_data.iter().zip(dst_data.iter()) {
+        assert_eq!(*dst, orient(src, width, height, 1, orientation));
+    }
+
+    let src_data = (0..planes)
+        .map(|p| {
+            (0..width * height)
+                .map(|i| (i * planes + p) as f32)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut dst_data = vec![vec![0f32; dst_width * dst_height]; planes];
+    let src = src_data.iter().map(|x| x.as_slice()).collect::<Vec<_>>();
+    let mut dst = dst_data
+        .iter_mut()
+        .map(|x| x.as_mut_slice())
+        .collect::<Vec<_>>();
+    orient_planes_f32(
+        &src,
+        &src_strides,
+        &mut dst,
+        &dst_strides,
+        width,
+        height,
+        orientation,
+    )
+    .unwrap();
+    for (src, dst) in src_data.iter().zip(dst_data.iter()) {
+        assert_eq!(*dst, orient(src, width, height, 1, orientation));
+    }
+});
@@ -32,7 +32,33 @@
 use fast_transpose::{transpose_rgba, FlipMode, FlopMode};
 use libfuzzer_sys::fuzz_target;
 
-fuzz_target!(|data: (u16, u16)| {
+fn reference(
+    src: &[u8],
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Vec<u8> {
+    let mut dst = vec![0u8; src.len()];
+    for y in 0..height {
+        for x in 0..width {
+            let dst_x = match flop_mode {
+                FlopMode::Flop => x,
+                FlopMode::NoFlop => width - 1 - x,
+            };
+            let dst_y = match flip_mode {
+                FlipMode::Flip => height - 1 - y,
+                FlipMode::NoFlip => y,
+            };
+            let dst_idx = (dst_x * height + dst_y) * 4;
+            let src_idx = (y * width + x) * 4;
+            dst[dst_idx..dst_idx + 4].copy_from_slice(&src[src_idx..src_idx + 4]);
+        }
+    }
+    dst
+}
+
+fuzz_target!(|data: (u16, u16, u8)| {
     let width = data.0 as usize;
     let height = data.1 as usize;
     if width > 512 || height > 512 {
@@ -41,41 +67,27 @@ fuzz_target!(|data: (u16, u16)| {
     if width == 0 || height == 0 {
         return;
     }
-    let src_data = vec![0u8; width * height * 4];
+    let src_data: Vec<u8> = (0..width * height * 4)
+        .map(|i| (i as u8).wrapping_mul(31).wrapping_add(data.2) ^ (i >> 8) as u8)
+        .collect();
     let mut dst_data = vec![0u8; width * height * 4];
-    transpose_rgba(
-        &src_data,
-        width * 4,
-        &mut dst_data,
-        height * 4,
-        width,
-        height,
-        FlipMode::NoFlip,
-        FlopMode::NoFlop,
-    )
-    .unwrap();
-
-    transpose_rgba(
-        &src_data,
-        width * 4,
-        &mut dst_data,
-        height * 4,
-        width,
-        height,
-        FlipMode::Flip,
-        FlopMode::NoFlop,
-    )
-    .unwrap();
-
-    transpose_rgba(
-        &src_data,
-        width * 4,
-        &mut dst_data,
-        height * 4,
-        width,
-        height,
-        FlipMode::Flip,
-        FlopMode::Flop,
-    )
-    .unwrap();
+    for flip_mode in [FlipMode::NoFlip, FlipMode::Flip] {
+        for flop_mode in [FlopMode::NoFlop, FlopMode::Flop] {
+            transpose_rgba(
+                &src_data,
+                width * 4,
+                &mut dst_data,
+                height * 4,
+                width,
+                height,
+                flip_mode,
+                flop_mode,
+            )
+            .unwrap();
+            assert_eq!(
+                dst_data,
+                reference(&src_data, width, height, flip_mode, flop_mode)
+            );
+        }
+    }
 });
@@ -0,0 +1,185 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::{
+    transpose_tiles, transpose_tiles_2d, FlipMode, FlopMode, TransposeError, TransposeTile,
+};
+use libfuzzer_sys::fuzz_target;
+
+type TileFn<T> =
+    fn(&mut TransposeTile<T>, &[T], usize, FlipMode, FlopMode) -> Result<(), TransposeError>;
+
+fn check<T: Copy + PartialEq + core::fmt::Debug>(
+    src: &[T],
+    src_stride: usize,
+    dst: &[T],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+    cn: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) {
+    for y in 0..height {
+        for x in 0..width {
+            let dst_y = match flop_mode {
+                FlopMode::Flop => x,
+                FlopMode::NoFlop => width - 1 - x,
+            };
+            let dst_x = match flip_mode {
+                FlipMode::NoFlip => y,
+                FlipMode::Flip => height - 1 - y,
+            };
+            assert_eq!(
+                dst[dst_y * dst_stride + dst_x * cn..][..cn],
+                src[y * src_stride + x * cn..][..cn]
+            );
+        }
+    }
+}
+
+fn exercise<T: Copy + Default + PartialEq + core::fmt::Debug>(
+    src: &[T],
+    width: usize,
+    height: usize,
+    cn: usize,
+    padding: usize,
+    tile_rows: usize,
+    tile_columns: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+    transpose: TileFn<T>,
+) {
+    let src_stride = width * cn;
+    let dst_stride = height * cn + padding;
+
+    let mut dst = vec![T::default(); dst_stride * width];
+    for mut tile in transpose_tiles(&mut dst, dst_stride, width, height, tile_rows).unwrap() {
+        transpose(&mut tile, src, src_stride, flip_mode, flop_mode).unwrap();
+    }
+    check(
+        src, src_stride, &dst, dst_stride, width, height, cn, flip_mode, flop_mode,
+    );
+
+    let mut dst = vec![T::default(); dst_stride * width];
+    let tiles = transpose_tiles_2d(
+        &mut dst,
+        dst_stride,
+        width,
+        height,
+        cn,
+        tile_rows,
+        tile_columns,
+    )
+    .unwrap();
+    let count = tiles.len();
+    let mut visited = 0usize;
+    for mut tile in tiles {
+        assert!(tile.rows() > 0 && tile.columns() > 0);
+        transpose(&mut tile, src, src_stride, flip_mode, flop_mode).unwrap();
+        visited += 1;
+    }
+    assert_eq!(count, visited);
+    check(
+        src, src_stride, &dst, dst_stride, width, height, cn, flip_mode, flop_mode,
+    );
+}
+
+fuzz_target!(|data: (u16, u16, u8, u8, u8, bool, bool)| {
+    let width = data.0 as usize;
+    let height = data.1 as usize;
+    if width > 512 || height > 512 {
+        return;
+    }
+    let tile_rows = data.2 as usize;
+    let tile_columns = data.3 as usize;
+    let padding = data.4 as usize % 3;
+    let flip_mode = if data.5 {
+        FlipMode::Flip
+    } else {
+        FlipMode::NoFlip
+    };
+    let flop_mode = if data.6 {
+        FlopMode::Flop
+    } else {
+        FlopMode::NoFlop
+    };
+
+    let src = (0..width * height)
+        .map(|i| (i ^ (i >> 8)) as u8)
+        .collect::<Vec<_>>();
+    exercise(
+        &src,
+        width,
+        height,
+        1,
+        padding,
+        tile_rows,
+        tile_columns,
+        flip_mode,
+        flop_mode,
+        |tile, src, stride, flip, flop| tile.transpose_plane(src, stride, flip, flop),
+    );
+
+    let src = (0..width * height * 4)
+        .map(|i| i as u16)
+        .collect::<Vec<_>>();
+    exercise(
+        &src,
+        width,
+        height,
+        4,
+        padding,
+        tile_rows,
+        tile_columns,
+        flip_mode,
+        flop_mode,
+        |tile, src, stride, flip, flop| tile.transpose_rgba16(src, stride, flip, flop),
+    );
+
+    let src = (0..width * height * 2)
+        .map(|i| i as f32)
+        .collect::<Vec<_>>();
+    exercise(
+        &src,
+        width,
+        height,
+        2,
+        padding,
+        tile_rows,
+        tile_columns,
+        flip_mode,
+        flop_mode,
+        |tile, src, stride, flip, flop| {
+            tile.transpose_plane_f32_with_alpha(src, stride, flip, flop)
+        },
+    );
+});
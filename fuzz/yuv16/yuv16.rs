@@ -0,0 +1,211 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#![no_main]
+
+use fast_transpose::{rotate_i210, rotate_p010, BitDepthCheck, Orientation, TransposeError};
+use libfuzzer_sys::fuzz_target;
+
+/// Returns position of source sample `(x, y)` in the oriented frame
+fn oriented_position(
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+) -> (usize, usize) {
+    match orientation {
+        Orientation::Identity => (x, y),
+        Orientation::Rotate90 => (height - 1 - y, x),
+        Orientation::Rotate180 => (width - 1 - x, height - 1 - y),
+        Orientation::Rotate270 => (y, width - 1 - x),
+        Orientation::Flip => (width - 1 - x, y),
+        Orientation::Flop => (x, height - 1 - y),
+        Orientation::Transpose => (y, x),
+        Orientation::Transverse => (height - 1 - y, width - 1 - x),
+    }
+}
+
+fn orient<T: Copy + Default>(
+    src: &[T],
+    width: usize,
+    height: usize,
+    cn: usize,
+    orientation: Orientation,
+) -> Vec<T> {
+    let (dst_width, _) = orientation.oriented_size(width, height);
+    let mut dst = vec![T::default(); width * height * cn];
+    for y in 0..height {
+        for x in 0..width {
+            let (dst_x, dst_y) = oriented_position(x, y, width, height, orientation);
+            let dst = &mut dst[(dst_y * dst_width + dst_x) * cn..][..cn];
+            dst.copy_from_slice(&src[(y * width + x) * cn..][..cn]);
+        }
+    }
+    dst
+}
+
+/// Checks that planes hold oriented source, masked when requested,
+/// or stay untouched when source doesn't fit bit depth with [BitDepthCheck::Verify]
+fn check(
+    result: Result<(), TransposeError>,
+    planes: &[(&[u16], &[u16], usize, usize, usize)],
+    orientation: Orientation,
+    valid_bits: u16,
+    bit_depth_check: BitDepthCheck,
+) {
+    let fits = planes
+        .iter()
+        .all(|&(src, ..)| src.iter().all(|&x| x & !valid_bits == 0));
+    if bit_depth_check == BitDepthCheck::Verify && !fits {
+        assert_eq!(result, Err(TransposeError::InvalidBitDepth));
+        for &(_, dst, ..) in planes {
+            assert!(dst.iter().all(|&x| x == 0));
+        }
+        return;
+    }
+    result.unwrap();
+    for &(src, dst, width, height, cn) in planes {
+        let mut expected = orient(src, width, height, cn, orientation);
+        if bit_depth_check == BitDepthCheck::Mask {
+            expected.iter_mut().for_each(|x| *x &= valid_bits);
+        }
+        assert_eq!(dst, expected);
+    }
+}
+
+fuzz_target!(|data: (u16, u16, u8, u8)| {
+    let width = data.0 as usize;
+    let height = data.1 as usize;
+    if width > 512 || height > 512 {
+        return;
+    }
+    if width == 0 || height == 0 {
+        return;
+    }
+    let orientation = match data.2 % 8 {
+        0 => Orientation::Identity,
+        1 => Orientation::Rotate90,
+        2 => Orientation::Rotate180,
+        3 => Orientation::Rotate270,
+        4 => Orientation::Flip,
+        5 => Orientation::Flop,
+        6 => Orientation::Transpose,
+        _ => Orientation::Transverse,
+    };
+    let bit_depth_check = match data.3 % 3 {
+        0 => BitDepthCheck::Unchecked,
+        1 => BitDepthCheck::Verify,
+        _ => BitDepthCheck::Mask,
+    };
+    let chroma_width = width.div_ceil(2);
+    let chroma_height = height.div_ceil(2);
+    let (dst_width, dst_height) = orientation.oriented_size(width, height);
+    let (dst_chroma_width, dst_chroma_height) =
+        orientation.oriented_size(chroma_width, chroma_height);
+
+    // Samples are either kept in bit depth of the format or use all 16 bits
+    let valid_bits = if data.3 >= 128 { 0xffc0 } else { 0xffff };
+    let src_y = (0..width * height)
+        .map(|i| (i * 97) as u16 & valid_bits)
+        .collect::<Vec<_>>();
+    let src_uv = (0..chroma_width * 2 * chroma_height)
+        .map(|i| (i * 89) as u16 & valid_bits)
+        .collect::<Vec<_>>();
+    let mut dst_y = vec![0u16; dst_width * dst_height];
+    let mut dst_uv = vec![0u16; dst_chroma_width * 2 * dst_chroma_height];
+    let result = rotate_p010(
+        &src_y,
+        width,
+        &src_uv,
+        chroma_width * 2,
+        &mut dst_y,
+        dst_width,
+        &mut dst_uv,
+        dst_chroma_width * 2,
+        width,
+        height,
+        orientation,
+        bit_depth_check,
+    );
+    check(
+        result,
+        &[
+            (&src_y, &dst_y, width, height, 1),
+            (&src_uv, &dst_uv, chroma_width, chroma_height, 2),
+        ],
+        orientation,
+        0xffc0,
+        bit_depth_check,
+    );
+
+    let valid_bits = if data.3 >= 128 { 0x03ff } else { 0xffff };
+    let src_y = (0..width * height)
+        .map(|i| (i * 97) as u16 & valid_bits)
+        .collect::<Vec<_>>();
+    dst_y.fill(0);
+    let (dst_chroma_width, dst_chroma_height) = orientation.oriented_size(chroma_width, height);
+    let src_u = (0..chroma_width * height)
+        .map(|i| (i * 83) as u16 & valid_bits)
+        .collect::<Vec<_>>();
+    let src_v = (0..chroma_width * height)
+        .map(|i| (i * 79) as u16 & valid_bits)
+        .collect::<Vec<_>>();
+    let mut dst_u = vec![0u16; dst_chroma_width * dst_chroma_height];
+    let mut dst_v = vec![0u16; dst_chroma_width * dst_chroma_height];
+    let result = rotate_i210(
+        &src_y,
+        width,
+        &src_u,
+        chroma_width,
+        &src_v,
+        chroma_width,
+        &mut dst_y,
+        dst_width,
+        &mut dst_u,
+        dst_chroma_width,
+        &mut dst_v,
+        dst_chroma_width,
+        width,
+        height,
+        orientation,
+        bit_depth_check,
+    );
+    check(
+        result,
+        &[
+            (&src_y, &dst_y, width, height, 1),
+            (&src_u, &dst_u, chroma_width, height, 1),
+            (&src_v, &dst_v, chroma_width, height, 1),
+        ],
+        orientation,
+        0x03ff,
+        bit_depth_check,
+    );
+});
@@ -0,0 +1,132 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::interleave::{deinterleave_pod_row, interleave_pod_row};
+use crate::transpose_arbitrary::transpose_pod_view;
+use crate::{FlipMode, FlopMode, TransposeError};
+use bytemuck::Pod;
+
+/// Checks that `T` is exactly `FIELDS` values of `V`
+fn check_layout<T: Pod, V: Pod, const FIELDS: usize>() -> Result<(), TransposeError> {
+    if FIELDS == 0 || size_of::<T>() != size_of::<[V; FIELDS]>() {
+        return Err(TransposeError::InvalidStructLayout);
+    }
+    Ok(())
+}
+
+/// Converts array of structs into struct of arrays
+///
+/// Every struct is treated as a row of `FIELDS` values of `V`, so this is a transpose
+/// of `input.len()` x `FIELDS` matrix. Field `i` of all structs is written
+/// into `output[i * input.len()..(i + 1) * input.len()]`.
+///
+/// # Arguments
+///
+/// * `input`: Structs, each one must be exactly `FIELDS` values of `V`
+/// * `output`: Fields planes, must be `FIELDS * input.len()` long
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn aos_to_soa<T: Pod, V: Pod, const FIELDS: usize>(
+    input: &[T],
+    output: &mut [V],
+) -> Result<(), TransposeError> {
+    check_layout::<T, V, FIELDS>()?;
+    let input =
+        bytemuck::try_cast_slice::<T, V>(input).map_err(|_| TransposeError::InvalidStructLayout)?;
+    let count = input.len() / FIELDS;
+    if output.len() != input.len() {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if count == 0 {
+        return Ok(());
+    }
+    if (2..=4).contains(&FIELDS) {
+        let mut planes = output.chunks_exact_mut(count);
+        let planes: [&mut [V]; FIELDS] = core::array::from_fn(|_| planes.next().unwrap());
+        if let Some(result) = deinterleave_pod_row(input, planes, count) {
+            return result;
+        }
+    }
+    transpose_pod_view(
+        input,
+        FIELDS,
+        output,
+        count,
+        FIELDS,
+        count,
+        FlipMode::NoFlip,
+        FlopMode::Flop,
+    )
+}
+
+/// Converts struct of arrays into array of structs
+///
+/// Inverse of [aos_to_soa], field `i` of all structs is read
+/// from `input[i * output.len()..(i + 1) * output.len()]`.
+///
+/// # Arguments
+///
+/// * `input`: Fields planes, must be `FIELDS * output.len()` long
+/// * `output`: Structs, each one must be exactly `FIELDS` values of `V`
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn soa_to_aos<T: Pod, V: Pod, const FIELDS: usize>(
+    input: &[V],
+    output: &mut [T],
+) -> Result<(), TransposeError> {
+    check_layout::<T, V, FIELDS>()?;
+    let output = bytemuck::try_cast_slice_mut::<T, V>(output)
+        .map_err(|_| TransposeError::InvalidStructLayout)?;
+    let count = output.len() / FIELDS;
+    if input.len() != output.len() {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if count == 0 {
+        return Ok(());
+    }
+    if (2..=4).contains(&FIELDS) {
+        let mut planes = input.chunks_exact(count);
+        let planes: [&[V]; FIELDS] = core::array::from_fn(|_| planes.next().unwrap());
+        if let Some(result) = interleave_pod_row(planes, output, count) {
+            return result;
+        }
+    }
+    transpose_pod_view(
+        input,
+        count,
+        output,
+        FIELDS,
+        count,
+        FIELDS,
+        FlipMode::NoFlip,
+        FlopMode::Flop,
+    )
+}
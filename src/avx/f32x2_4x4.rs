@@ -28,7 +28,10 @@
  */
 
 use crate::avx::u16x4_4x4::avx_transpose_u64_4x4_impl;
-use std::arch::x86_64::*;
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
 
 #[inline]
 #[target_feature(enable = "avx2")]
@@ -0,0 +1,92 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[inline(always)]
+unsafe fn avx_interleave_f64x2<const FLIP: bool>(
+    v0: (__m256d, __m256d, __m256d, __m256d),
+) -> (__m256d, __m256d, __m256d, __m256d) {
+    // Every pixel is a 128 bit lane, rows of four pixels are split into
+    // low and high halves. Goes from:
+    // lo: 00 01 | 10 11
+    // hi: 20 21 | 30 31
+    // to:
+    // out[0]: 00 10, out[1]: 20 30
+    // out[2]: 01 11, out[3]: 21 31
+    if FLIP {
+        (
+            _mm256_permute2f128_pd::<0x02>(v0.2, v0.3),
+            _mm256_permute2f128_pd::<0x02>(v0.0, v0.1),
+            _mm256_permute2f128_pd::<0x13>(v0.2, v0.3),
+            _mm256_permute2f128_pd::<0x13>(v0.0, v0.1),
+        )
+    } else {
+        (
+            _mm256_permute2f128_pd::<0x20>(v0.0, v0.1),
+            _mm256_permute2f128_pd::<0x20>(v0.2, v0.3),
+            _mm256_permute2f128_pd::<0x31>(v0.0, v0.1),
+            _mm256_permute2f128_pd::<0x31>(v0.2, v0.3),
+        )
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx")]
+pub(crate) unsafe fn avx_transpose_f64x2_4x4<const FLOP: bool, const FLIP: bool>(
+    src: &[f64],
+    src_stride: usize,
+    dst: &mut [f64],
+    dst_stride: usize,
+) {
+    unsafe {
+        let mut rows = [_mm256_setzero_pd(); 8];
+        for (i, row) in rows.chunks_exact_mut(2).enumerate() {
+            let src = src.get_unchecked(i * src_stride..);
+            row[0] = _mm256_loadu_pd(src.as_ptr());
+            row[1] = _mm256_loadu_pd(src.get_unchecked(4..).as_ptr());
+        }
+
+        // Columns 0 and 1 of the block are in the low halves of the rows,
+        // columns 2 and 3 in the high halves.
+        let lo = avx_interleave_f64x2::<FLIP>((rows[0], rows[2], rows[4], rows[6]));
+        let hi = avx_interleave_f64x2::<FLIP>((rows[1], rows[3], rows[5], rows[7]));
+
+        let columns = [(lo.0, lo.1), (lo.2, lo.3), (hi.0, hi.1), (hi.2, hi.3)];
+
+        for (i, column) in columns.iter().enumerate() {
+            let dst_row = if FLOP { i } else { 3 - i };
+            let dst = dst.get_unchecked_mut(dst_row * dst_stride..);
+            _mm256_storeu_pd(dst.as_mut_ptr(), column.0);
+            _mm256_storeu_pd(dst.get_unchecked_mut(4..).as_mut_ptr(), column.1);
+        }
+    }
+}
@@ -0,0 +1,250 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::sse::{deinterleave_bytes, interleave_bytes, is_empty_mask, ShuffleMasks};
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+// Shuffles of AVX2 don't cross 128 bit lanes, so every lane runs the same
+// conversion as SSSE3 does, low lanes on one block of pixels and high lanes
+// on the next one.
+
+#[inline(always)]
+unsafe fn load_mask(mask: &[u8; 16]) -> __m256i {
+    _mm256_broadcastsi128_si256(_mm_loadu_si128(mask.as_ptr() as *const _))
+}
+
+/// Gathers register of the other layout from `CN` registers
+#[inline(always)]
+unsafe fn shuffle_gather<const CN: usize>(v: &[__m256i; CN], masks: &[[u8; 16]; CN]) -> __m256i {
+    let mut acc = _mm256_setzero_si256();
+    for (&v, mask) in v.iter().zip(masks.iter()) {
+        if !is_empty_mask(mask) {
+            acc = _mm256_or_si256(acc, _mm256_shuffle_epi8(v, load_mask(mask)));
+        }
+    }
+    acc
+}
+
+#[inline(always)]
+unsafe fn transpose_4x4_epi32(v: [__m256i; 4]) -> [__m256i; 4] {
+    let t0 = _mm256_unpacklo_epi32(v[0], v[1]);
+    let t1 = _mm256_unpackhi_epi32(v[0], v[1]);
+    let t2 = _mm256_unpacklo_epi32(v[2], v[3]);
+    let t3 = _mm256_unpackhi_epi32(v[2], v[3]);
+    [
+        _mm256_unpacklo_epi64(t0, t2),
+        _mm256_unpackhi_epi64(t0, t2),
+        _mm256_unpacklo_epi64(t1, t3),
+        _mm256_unpackhi_epi64(t1, t3),
+    ]
+}
+
+/// Converts `CN` interleaved registers into `CN` planar registers
+#[inline(always)]
+unsafe fn deinterleave_registers<const CN: usize, const S: usize>(
+    v: [__m256i; CN],
+) -> [__m256i; CN] {
+    match CN {
+        2 => {
+            let group = load_mask(&ShuffleMasks::<CN, S>::GROUP);
+            let g0 = _mm256_shuffle_epi8(v[0], group);
+            let g1 = _mm256_shuffle_epi8(v[1], group);
+            let planes = [_mm256_unpacklo_epi64(g0, g1), _mm256_unpackhi_epi64(g0, g1)];
+            core::array::from_fn(|i| planes[i])
+        }
+        4 => {
+            let group = load_mask(&ShuffleMasks::<CN, S>::GROUP);
+            let planes = transpose_4x4_epi32([
+                _mm256_shuffle_epi8(v[0], group),
+                _mm256_shuffle_epi8(v[1], group),
+                _mm256_shuffle_epi8(v[2], group),
+                _mm256_shuffle_epi8(v[3], group),
+            ]);
+            core::array::from_fn(|i| planes[i])
+        }
+        _ => {
+            let mut out = [_mm256_setzero_si256(); CN];
+            for (out, masks) in out
+                .iter_mut()
+                .zip(ShuffleMasks::<CN, S>::DEINTERLEAVE.iter())
+            {
+                *out = shuffle_gather(&v, masks);
+            }
+            out
+        }
+    }
+}
+
+/// Converts `CN` planar registers into `CN` interleaved registers
+#[inline(always)]
+unsafe fn interleave_registers<const CN: usize, const S: usize>(v: [__m256i; CN]) -> [__m256i; CN] {
+    match CN {
+        2 => {
+            let interleaved = match S {
+                1 => [
+                    _mm256_unpacklo_epi8(v[0], v[1]),
+                    _mm256_unpackhi_epi8(v[0], v[1]),
+                ],
+                2 => [
+                    _mm256_unpacklo_epi16(v[0], v[1]),
+                    _mm256_unpackhi_epi16(v[0], v[1]),
+                ],
+                _ => [
+                    _mm256_unpacklo_epi32(v[0], v[1]),
+                    _mm256_unpackhi_epi32(v[0], v[1]),
+                ],
+            };
+            core::array::from_fn(|i| interleaved[i])
+        }
+        4 => {
+            let ungroup = load_mask(&ShuffleMasks::<CN, S>::UNGROUP);
+            let grouped = transpose_4x4_epi32([v[0], v[1], v[2], v[3]]);
+            core::array::from_fn(|i| _mm256_shuffle_epi8(grouped[i], ungroup))
+        }
+        _ => {
+            let mut out = [_mm256_setzero_si256(); CN];
+            for (out, masks) in out.iter_mut().zip(ShuffleMasks::<CN, S>::INTERLEAVE.iter()) {
+                *out = shuffle_gather(&v, masks);
+            }
+            out
+        }
+    }
+}
+
+#[inline(always)]
+unsafe fn avx2_deinterleave_bytes<const CN: usize, const S: usize>(
+    src: *const u8,
+    dst: [*mut u8; CN],
+    width: usize,
+) -> usize {
+    let lanes = 32 / S;
+    let mut x = 0usize;
+    while x + lanes <= width {
+        let src = src.add(x * CN * S);
+        // Low lanes take the first block of pixels, high lanes the second one
+        let v: [__m256i; CN] = core::array::from_fn(|i| {
+            _mm256_loadu2_m128i(
+                src.add((CN + i) * 16) as *const _,
+                src.add(i * 16) as *const _,
+            )
+        });
+        let planar = deinterleave_registers::<CN, S>(v);
+        for (&v, &dst) in planar.iter().zip(dst.iter()) {
+            _mm256_storeu_si256(dst.add(x * S) as *mut _, v);
+        }
+        x += lanes;
+    }
+    x + deinterleave_bytes::<CN, S>(
+        src.add(x * CN * S),
+        dst.map(|dst| dst.add(x * S)),
+        width - x,
+    )
+}
+
+#[inline(always)]
+unsafe fn avx2_interleave_bytes<const CN: usize, const S: usize>(
+    src: [*const u8; CN],
+    dst: *mut u8,
+    width: usize,
+) -> usize {
+    let lanes = 32 / S;
+    let mut x = 0usize;
+    while x + lanes <= width {
+        let v: [__m256i; CN] =
+            core::array::from_fn(|i| _mm256_loadu_si256(src[i].add(x * S) as *const _));
+        let dst = dst.add(x * CN * S);
+        let interleaved = interleave_registers::<CN, S>(v);
+        for (i, &v) in interleaved.iter().enumerate() {
+            _mm256_storeu2_m128i(
+                dst.add((CN + i) * 16) as *mut _,
+                dst.add(i * 16) as *mut _,
+                v,
+            );
+        }
+        x += lanes;
+    }
+    x + interleave_bytes::<CN, S>(
+        src.map(|src| src.add(x * S)),
+        dst.add(x * CN * S),
+        width - x,
+    )
+}
+
+/// Splits `width` pixels of `CN` interleaved channels into `CN` planes,
+/// `CN` is 2, 3 or 4 and samples are 1, 2 or 4 bytes wide.
+///
+/// Returns amount of pixels processed, rest is left to the caller,
+/// other layouts are not processed at all.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn avx2_deinterleave_row<V: Copy, const CN: usize>(
+    src: &[V],
+    dst: &mut [&mut [V]; CN],
+    width: usize,
+) -> usize {
+    if !(2..=4).contains(&CN) {
+        return 0;
+    }
+    let src = &src[..width * CN];
+    let dst: [*mut u8; CN] = core::array::from_fn(|i| dst[i][..width].as_mut_ptr() as *mut u8);
+    let src = src.as_ptr() as *const u8;
+    match size_of::<V>() {
+        1 => avx2_deinterleave_bytes::<CN, 1>(src, dst, width),
+        2 => avx2_deinterleave_bytes::<CN, 2>(src, dst, width),
+        4 => avx2_deinterleave_bytes::<CN, 4>(src, dst, width),
+        _ => 0,
+    }
+}
+
+/// Merges `CN` planes into `width` pixels of `CN` interleaved channels,
+/// `CN` is 2, 3 or 4 and samples are 1, 2 or 4 bytes wide.
+///
+/// Returns amount of pixels processed, rest is left to the caller,
+/// other layouts are not processed at all.
+#[inline]
+#[target_feature(enable = "avx2")]
+pub(crate) unsafe fn avx2_interleave_row<V: Copy, const CN: usize>(
+    src: &[&[V]; CN],
+    dst: &mut [V],
+    width: usize,
+) -> usize {
+    if !(2..=4).contains(&CN) {
+        return 0;
+    }
+    let src: [*const u8; CN] = core::array::from_fn(|i| src[i][..width].as_ptr() as *const u8);
+    let dst = dst[..width * CN].as_mut_ptr() as *mut u8;
+    match size_of::<V>() {
+        1 => avx2_interleave_bytes::<CN, 1>(src, dst, width),
+        2 => avx2_interleave_bytes::<CN, 2>(src, dst, width),
+        4 => avx2_interleave_bytes::<CN, 4>(src, dst, width),
+        _ => 0,
+    }
+}
@@ -27,11 +27,17 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 mod f32x2_4x4;
+mod f64x2_4x4;
+mod interleave;
 mod u16x4_4x4;
+mod x4_f64;
 mod x8_f32;
 mod x8_u32;
 
 pub(crate) use f32x2_4x4::avx2_transpose_f32x2_4x4;
+pub(crate) use f64x2_4x4::avx_transpose_f64x2_4x4;
+pub(crate) use interleave::{avx2_deinterleave_row, avx2_interleave_row};
 pub(crate) use u16x4_4x4::avx2_transpose_u16x4_4x4;
+pub(crate) use x4_f64::avx_transpose_4x4_f64;
 pub(crate) use x8_f32::avx_transpose_8x8_f32;
 pub(crate) use x8_u32::avx_transpose_8x8_u32;
@@ -28,7 +28,10 @@
  */
 
 use crate::sse::_mm_shuffle;
-use std::arch::x86_64::*;
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
 
 #[inline(always)]
 pub(crate) unsafe fn avx_transpose_u64_4x4_impl<const FLIP: bool>(
@@ -0,0 +1,109 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+#[inline(always)]
+unsafe fn avx_reverse_f64(v: __m256d) -> __m256d {
+    let swapped_lanes = _mm256_permute2f128_pd::<0x01>(v, v);
+    _mm256_permute_pd::<0b0101>(swapped_lanes)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn avx_transpose_4x4_impl_f64<const FLIP: bool>(
+    v0: (__m256d, __m256d, __m256d, __m256d),
+) -> (__m256d, __m256d, __m256d, __m256d) {
+    // Unpack 64 bit elements within 128 bit lanes. Goes from:
+    // in[0]: 00 01 02 03
+    // in[1]: 10 11 12 13
+    // in[2]: 20 21 22 23
+    // in[3]: 30 31 32 33
+    // to:
+    // a0:    00 10 02 12
+    // a1:    01 11 03 13
+    // a2:    20 30 22 32
+    // a3:    21 31 23 33
+    let a0 = _mm256_unpacklo_pd(v0.0, v0.1);
+    let a1 = _mm256_unpackhi_pd(v0.0, v0.1);
+    let a2 = _mm256_unpacklo_pd(v0.2, v0.3);
+    let a3 = _mm256_unpackhi_pd(v0.2, v0.3);
+
+    // Permute 128 bit lanes resulting in:
+    // out[0]: 00 10 20 30
+    // out[1]: 01 11 21 31
+    // out[2]: 02 12 22 32
+    // out[3]: 03 13 23 33
+    let r0 = _mm256_permute2f128_pd::<0x20>(a0, a2);
+    let r1 = _mm256_permute2f128_pd::<0x20>(a1, a3);
+    let r2 = _mm256_permute2f128_pd::<0x31>(a0, a2);
+    let r3 = _mm256_permute2f128_pd::<0x31>(a1, a3);
+
+    if FLIP {
+        (
+            avx_reverse_f64(r0),
+            avx_reverse_f64(r1),
+            avx_reverse_f64(r2),
+            avx_reverse_f64(r3),
+        )
+    } else {
+        (r0, r1, r2, r3)
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx")]
+pub(crate) unsafe fn avx_transpose_4x4_f64<const FLOP: bool, const FLIP: bool>(
+    src: &[f64],
+    src_stride: usize,
+    dst: &mut [f64],
+    dst_stride: usize,
+) {
+    unsafe {
+        let row0 = _mm256_loadu_pd(src.get_unchecked(0..).as_ptr());
+        let row1 = _mm256_loadu_pd(src.get_unchecked(src_stride..).as_ptr());
+        let row2 = _mm256_loadu_pd(src.get_unchecked(2 * src_stride..).as_ptr());
+        let row3 = _mm256_loadu_pd(src.get_unchecked(3 * src_stride..).as_ptr());
+
+        let v0 = avx_transpose_4x4_impl_f64::<FLIP>((row0, row1, row2, row3));
+
+        if FLOP {
+            _mm256_storeu_pd(dst.get_unchecked_mut(0..).as_mut_ptr(), v0.0);
+            _mm256_storeu_pd(dst.get_unchecked_mut(dst_stride..).as_mut_ptr(), v0.1);
+            _mm256_storeu_pd(dst.get_unchecked_mut(2 * dst_stride..).as_mut_ptr(), v0.2);
+            _mm256_storeu_pd(dst.get_unchecked_mut(3 * dst_stride..).as_mut_ptr(), v0.3);
+        } else {
+            _mm256_storeu_pd(dst.get_unchecked_mut(3 * dst_stride..).as_mut_ptr(), v0.0);
+            _mm256_storeu_pd(dst.get_unchecked_mut(2 * dst_stride..).as_mut_ptr(), v0.1);
+            _mm256_storeu_pd(dst.get_unchecked_mut(dst_stride..).as_mut_ptr(), v0.2);
+            _mm256_storeu_pd(dst.get_unchecked_mut(0..).as_mut_ptr(), v0.3);
+        }
+    }
+}
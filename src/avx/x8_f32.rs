@@ -28,7 +28,10 @@
  */
 
 use crate::sse::_mm_shuffle;
-use std::arch::x86_64::*;
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
 
 #[inline]
 #[target_feature(enable = "avx2")]
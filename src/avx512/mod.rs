@@ -27,5 +27,7 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 mod x16_u32;
+mod x8_f64;
 
 pub(crate) use x16_u32::avx512_transpose_16x16_u32;
+pub(crate) use x8_f64::avx512_transpose_8x8_f64;
@@ -27,7 +27,7 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 use crate::sse::_mm_shuffle;
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 #[inline(always)]
 #[allow(clippy::type_complexity)]
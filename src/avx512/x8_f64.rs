@@ -0,0 +1,109 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use core::arch::x86_64::*;
+
+#[inline(always)]
+unsafe fn avx512_transpose_8x8_impl_f64<const FLIP: bool>(v: [__m512d; 8]) -> [__m512d; 8] {
+    // Unpack 64 bit elements within 128 bit lanes, e.g. rows 0 and 1 go to:
+    // a0: 00 10 02 12 04 14 06 16
+    // a1: 01 11 03 13 05 15 07 17
+    let a0 = _mm512_unpacklo_pd(v[0], v[1]);
+    let a1 = _mm512_unpackhi_pd(v[0], v[1]);
+    let a2 = _mm512_unpacklo_pd(v[2], v[3]);
+    let a3 = _mm512_unpackhi_pd(v[2], v[3]);
+    let a4 = _mm512_unpacklo_pd(v[4], v[5]);
+    let a5 = _mm512_unpackhi_pd(v[4], v[5]);
+    let a6 = _mm512_unpacklo_pd(v[6], v[7]);
+    let a7 = _mm512_unpackhi_pd(v[6], v[7]);
+
+    // Gather even and odd 128 bit lanes, e.g. rows 0..3 go to:
+    // b0: 00 10 04 14 20 30 24 34
+    // b1: 02 12 06 16 22 32 26 36
+    // b2: 01 11 05 15 21 31 25 35
+    // b3: 03 13 07 17 23 33 27 37
+    let b0 = _mm512_shuffle_f64x2::<0x88>(a0, a2);
+    let b1 = _mm512_shuffle_f64x2::<0xDD>(a0, a2);
+    let b2 = _mm512_shuffle_f64x2::<0x88>(a1, a3);
+    let b3 = _mm512_shuffle_f64x2::<0xDD>(a1, a3);
+    let b4 = _mm512_shuffle_f64x2::<0x88>(a4, a6);
+    let b5 = _mm512_shuffle_f64x2::<0xDD>(a4, a6);
+    let b6 = _mm512_shuffle_f64x2::<0x88>(a5, a7);
+    let b7 = _mm512_shuffle_f64x2::<0xDD>(a5, a7);
+
+    // Gather even and odd 128 bit lanes once more, resulting in columns:
+    // out[0]: 00 10 20 30 40 50 60 70
+    let r = [
+        _mm512_shuffle_f64x2::<0x88>(b0, b4),
+        _mm512_shuffle_f64x2::<0x88>(b2, b6),
+        _mm512_shuffle_f64x2::<0x88>(b1, b5),
+        _mm512_shuffle_f64x2::<0x88>(b3, b7),
+        _mm512_shuffle_f64x2::<0xDD>(b0, b4),
+        _mm512_shuffle_f64x2::<0xDD>(b2, b6),
+        _mm512_shuffle_f64x2::<0xDD>(b1, b5),
+        _mm512_shuffle_f64x2::<0xDD>(b3, b7),
+    ];
+
+    if FLIP {
+        let rev = _mm512_setr_epi64(7, 6, 5, 4, 3, 2, 1, 0);
+        [
+            _mm512_permutexvar_pd(rev, r[0]),
+            _mm512_permutexvar_pd(rev, r[1]),
+            _mm512_permutexvar_pd(rev, r[2]),
+            _mm512_permutexvar_pd(rev, r[3]),
+            _mm512_permutexvar_pd(rev, r[4]),
+            _mm512_permutexvar_pd(rev, r[5]),
+            _mm512_permutexvar_pd(rev, r[6]),
+            _mm512_permutexvar_pd(rev, r[7]),
+        ]
+    } else {
+        r
+    }
+}
+
+#[inline(always)]
+pub(crate) fn avx512_transpose_8x8_f64<const FLOP: bool, const FLIP: bool>(
+    src: &[f64],
+    src_stride: usize,
+    dst: &mut [f64],
+    dst_stride: usize,
+) {
+    unsafe {
+        let mut rows = [_mm512_setzero_pd(); 8];
+        for (i, row) in rows.iter_mut().enumerate() {
+            *row = _mm512_loadu_pd(src.get_unchecked(i * src_stride..).as_ptr());
+        }
+
+        let v0 = avx512_transpose_8x8_impl_f64::<FLIP>(rows);
+
+        for (i, v) in v0.iter().enumerate() {
+            let row = if FLOP { i } else { 7 - i };
+            _mm512_storeu_pd(dst.get_unchecked_mut(row * dst_stride..).as_mut_ptr(), *v);
+        }
+    }
+}
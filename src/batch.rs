@@ -0,0 +1,498 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::utils::cast_pod_slices;
+use crate::TransposeError;
+use bytemuck::Pod;
+use core::any::Any;
+
+/// Defines function transposing every `N`x`N` block of a slice with x86 kernel,
+/// larger blocks are transposed by `K`x`K` parts with swapped positions.
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse"
+))]
+macro_rules! define_batch_x86 {
+    ($name:ident, $feature:literal, $v:ty, $n:expr, $module:ident, $kernel:ident) => {
+        define_batch_x86!($name, $feature, $v, $n, $n, $module, $kernel);
+    };
+    ($name:ident, $feature:literal, $v:ty, $n:expr, $k:expr, $module:ident, $kernel:ident) => {
+        #[target_feature(enable = $feature)]
+        pub(super) unsafe fn $name(src: &[$v], dst: &mut [$v]) {
+            for (src, dst) in src.chunks_exact($n * $n).zip(dst.chunks_exact_mut($n * $n)) {
+                for y in (0..$n).step_by($k) {
+                    for x in (0..$n).step_by($k) {
+                        crate::$module::$kernel::<true, false>(
+                            &src[y * $n + x..],
+                            $n,
+                            &mut dst[x * $n + y..],
+                            $n,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+/// Defines function transposing every `N`x`N` block of a slice with NEON kernel,
+/// larger blocks are transposed by `K`x`K` parts with swapped positions.
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+macro_rules! define_batch_neon {
+    ($name:ident, $v:ty, $n:expr, $kernel:ident) => {
+        define_batch_neon!($name, $v, $n, $n, $kernel);
+    };
+    ($name:ident, $v:ty, $n:expr, $k:expr, $kernel:ident) => {
+        pub(super) fn $name(src: &[$v], dst: &mut [$v]) {
+            for (src, dst) in src.chunks_exact($n * $n).zip(dst.chunks_exact_mut($n * $n)) {
+                for y in (0..$n).step_by($k) {
+                    for x in (0..$n).step_by($k) {
+                        crate::neon::$kernel::<true, false>(
+                            &src[y * $n + x..],
+                            $n,
+                            &mut dst[x * $n + y..],
+                            $n,
+                        );
+                    }
+                }
+            }
+        }
+    };
+}
+
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+mod neon {
+    define_batch_neon!(batch_u8_8x8_neon, u8, 8, neon_transpose_u8_8x8);
+    define_batch_neon!(batch_u8_16x16_neon, u8, 16, neon_transpose_u8_16x16);
+    define_batch_neon!(batch_u16_4x4_neon, u16, 4, neon_transpose_4x4_u16);
+    define_batch_neon!(batch_u16_8x8_neon, u16, 8, neon_transpose_8x8_u16);
+    define_batch_neon!(batch_f32_4x4_neon, f32, 4, neon_transpose_4x4_f32);
+    define_batch_neon!(batch_f32_8x8_neon, f32, 8, neon_transpose_8x8_f32);
+    define_batch_neon!(batch_f32_16x16_neon, f32, 16, 8, neon_transpose_8x8_f32);
+    define_batch_neon!(batch_f64_2x2_neon, f64, 2, neon_transpose_2x2_f64);
+    define_batch_neon!(batch_f64_4x4_neon, f64, 4, 2, neon_transpose_2x2_f64);
+    define_batch_neon!(batch_f64_8x8_neon, f64, 8, 2, neon_transpose_2x2_f64);
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse"
+))]
+mod sse {
+    define_batch_x86!(
+        batch_u8_8x8_ssse3,
+        "ssse3",
+        u8,
+        8,
+        sse,
+        sse_transpose_u8_8x8
+    );
+    define_batch_x86!(
+        batch_u8_16x16_ssse3,
+        "ssse3",
+        u8,
+        16,
+        8,
+        sse,
+        sse_transpose_u8_8x8
+    );
+    define_batch_x86!(
+        batch_u16_4x4_ssse3,
+        "ssse3",
+        u16,
+        4,
+        sse,
+        sse_transpose_4x4_u16
+    );
+    define_batch_x86!(
+        batch_u16_8x8_ssse3,
+        "ssse3",
+        u16,
+        8,
+        sse,
+        sse_transpose_8x8_u16
+    );
+    define_batch_x86!(
+        batch_f32_4x4_ssse3,
+        "ssse3",
+        f32,
+        4,
+        sse,
+        sse_transpose_4x4_f32
+    );
+    define_batch_x86!(
+        batch_f32_8x8_ssse3,
+        "ssse3",
+        f32,
+        8,
+        sse,
+        sse_transpose_8x8_f32
+    );
+    define_batch_x86!(
+        batch_f32_16x16_ssse3,
+        "ssse3",
+        f32,
+        16,
+        8,
+        sse,
+        sse_transpose_8x8_f32
+    );
+    define_batch_x86!(
+        batch_f64_2x2_sse2,
+        "sse2",
+        f64,
+        2,
+        sse,
+        sse_transpose_2x2_f64
+    );
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
+mod avx {
+    define_batch_x86!(
+        batch_f32_8x8_avx2,
+        "avx2",
+        f32,
+        8,
+        avx,
+        avx_transpose_8x8_f32
+    );
+    define_batch_x86!(
+        batch_f32_16x16_avx2,
+        "avx2",
+        f32,
+        16,
+        8,
+        avx,
+        avx_transpose_8x8_f32
+    );
+    define_batch_x86!(batch_f64_4x4_avx, "avx", f64, 4, avx, avx_transpose_4x4_f64);
+    define_batch_x86!(
+        batch_f64_8x8_avx,
+        "avx",
+        f64,
+        8,
+        4,
+        avx,
+        avx_transpose_4x4_f64
+    );
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "nightly_avx512"))]
+mod avx512 {
+    define_batch_x86!(
+        batch_f64_8x8_avx512,
+        "avx512f",
+        f64,
+        8,
+        avx512,
+        avx512_transpose_8x8_f64
+    );
+
+    #[target_feature(enable = "avx512bw")]
+    pub(super) unsafe fn batch_u32_16x16_avx512(src: &[u8], dst: &mut [u8]) {
+        const BLOCK: usize = 16 * 16 * 4;
+        for (src, dst) in src.chunks_exact(BLOCK).zip(dst.chunks_exact_mut(BLOCK)) {
+            crate::avx512::avx512_transpose_16x16_u32::<true, false>(src, 16 * 4, dst, 16 * 4);
+        }
+    }
+}
+
+/// Transposes `n`x`n` blocks of bytes in registers, returns `false` if there is no kernel
+#[allow(unused_variables)]
+fn batch_u8(src: &[u8], dst: &mut [u8], n: usize) -> bool {
+    #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+    match n {
+        8 => {
+            neon::batch_u8_8x8_neon(src, dst);
+            return true;
+        }
+        16 => {
+            neon::batch_u8_16x16_neon(src, dst);
+            return true;
+        }
+        _ => {}
+    }
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "sse"
+    ))]
+    if (n == 8 || n == 16) && crate::utils::has_x86_feature!("ssse3") {
+        if n == 8 {
+            unsafe { sse::batch_u8_8x8_ssse3(src, dst) };
+        } else {
+            unsafe { sse::batch_u8_16x16_ssse3(src, dst) };
+        }
+        return true;
+    }
+    false
+}
+
+/// Transposes `n`x`n` blocks of 2 bytes elements in registers, returns `false` if there is no kernel
+#[allow(unused_variables)]
+fn batch_u16(src: &[u16], dst: &mut [u16], n: usize) -> bool {
+    #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+    match n {
+        4 => {
+            neon::batch_u16_4x4_neon(src, dst);
+            return true;
+        }
+        8 => {
+            neon::batch_u16_8x8_neon(src, dst);
+            return true;
+        }
+        _ => {}
+    }
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "sse"
+    ))]
+    if (n == 4 || n == 8) && crate::utils::has_x86_feature!("ssse3") {
+        if n == 4 {
+            unsafe { sse::batch_u16_4x4_ssse3(src, dst) };
+        } else {
+            unsafe { sse::batch_u16_8x8_ssse3(src, dst) };
+        }
+        return true;
+    }
+    false
+}
+
+/// Transposes `n`x`n` blocks of 4 bytes elements in registers, returns `false` if there is no kernel
+#[allow(unused_variables)]
+fn batch_f32(src: &[f32], dst: &mut [f32], n: usize) -> bool {
+    #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+    match n {
+        4 => {
+            neon::batch_f32_4x4_neon(src, dst);
+            return true;
+        }
+        8 => {
+            neon::batch_f32_8x8_neon(src, dst);
+            return true;
+        }
+        16 => {
+            neon::batch_f32_16x16_neon(src, dst);
+            return true;
+        }
+        _ => {}
+    }
+    #[cfg(all(target_arch = "x86_64", feature = "nightly_avx512"))]
+    if n == 16 && crate::utils::has_x86_feature!("avx512bw") {
+        unsafe {
+            avx512::batch_u32_16x16_avx512(bytemuck::cast_slice(src), bytemuck::cast_slice_mut(dst))
+        };
+        return true;
+    }
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "avx"
+    ))]
+    if (n == 8 || n == 16) && crate::utils::has_x86_feature!("avx2") {
+        if n == 8 {
+            unsafe { avx::batch_f32_8x8_avx2(src, dst) };
+        } else {
+            unsafe { avx::batch_f32_16x16_avx2(src, dst) };
+        }
+        return true;
+    }
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "sse"
+    ))]
+    if (n == 4 || n == 8 || n == 16) && crate::utils::has_x86_feature!("ssse3") {
+        match n {
+            4 => unsafe { sse::batch_f32_4x4_ssse3(src, dst) },
+            8 => unsafe { sse::batch_f32_8x8_ssse3(src, dst) },
+            _ => unsafe { sse::batch_f32_16x16_ssse3(src, dst) },
+        }
+        return true;
+    }
+    false
+}
+
+/// Transposes `n`x`n` blocks of 8 bytes elements in registers, returns `false` if there is no kernel
+#[allow(unused_variables)]
+fn batch_f64(src: &[f64], dst: &mut [f64], n: usize) -> bool {
+    #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+    match n {
+        2 => {
+            neon::batch_f64_2x2_neon(src, dst);
+            return true;
+        }
+        4 => {
+            neon::batch_f64_4x4_neon(src, dst);
+            return true;
+        }
+        8 => {
+            neon::batch_f64_8x8_neon(src, dst);
+            return true;
+        }
+        _ => {}
+    }
+    #[cfg(all(target_arch = "x86_64", feature = "nightly_avx512"))]
+    if n == 8 && crate::utils::has_x86_feature!("avx512f") {
+        unsafe { avx512::batch_f64_8x8_avx512(src, dst) };
+        return true;
+    }
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "avx"
+    ))]
+    if (n == 4 || n == 8) && crate::utils::has_x86_feature!("avx") {
+        if n == 4 {
+            unsafe { avx::batch_f64_4x4_avx(src, dst) };
+        } else {
+            unsafe { avx::batch_f64_8x8_avx(src, dst) };
+        }
+        return true;
+    }
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "sse"
+    ))]
+    if n == 2 && crate::utils::has_x86_feature!("sse2") {
+        unsafe { sse::batch_f64_2x2_sse2(src, dst) };
+        return true;
+    }
+    false
+}
+
+/// Transposes square `n`x`n` blocks with kernel of the same element size,
+/// returns `false` if there is no such kernel or slices aren't aligned for it.
+pub(crate) fn transpose_square_batch<T: Pod>(src: &[T], dst: &mut [T], n: usize) -> bool {
+    match size_of::<T>() {
+        1 => cast_pod_slices::<T, u8>(src, dst).is_some_and(|(src, dst)| batch_u8(src, dst, n)),
+        2 => cast_pod_slices::<T, u16>(src, dst).is_some_and(|(src, dst)| batch_u16(src, dst, n)),
+        4 => cast_pod_slices::<T, f32>(src, dst).is_some_and(|(src, dst)| batch_f32(src, dst, n)),
+        8 => cast_pod_slices::<T, f64>(src, dst).is_some_and(|(src, dst)| batch_f64(src, dst, n)),
+        _ => false,
+    }
+}
+
+/// Performs transposition of every matrix in a batch of small contiguous matrices
+///
+/// Each block is transposed directly with an in-register kernel when one exists
+/// for its shape and element size, e.g. 8x8 and 16x16 of bytes, 4x4 and 8x8 of `u16`,
+/// 4x4 to 16x16 of `f32` and 2x2 to 8x8 of `f64`, other shapes are copied element by element.
+///
+/// # Arguments
+///
+/// * `src`: Source matrices of `R` rows and `C` columns
+/// * `dst`: Destination matrices of `C` rows and `R` columns, same count as `src`
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_batch<T: Pod, const R: usize, const C: usize>(
+    src: &[[[T; C]; R]],
+    dst: &mut [[[T; R]; C]],
+) -> Result<(), TransposeError> {
+    if src.len() != dst.len() {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if R == C
+        && transpose_square_batch(
+            src.as_flattened().as_flattened(),
+            dst.as_flattened_mut().as_flattened_mut(),
+            R,
+        )
+    {
+        return Ok(());
+    }
+    for (src, dst) in src.iter().zip(dst.iter_mut()) {
+        for (y, row) in src.iter().enumerate() {
+            for (x, &v) in row.iter().enumerate() {
+                dst[x][y] = v;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Transposes square matrix with kernel for elements of type `U`,
+/// returns `false` if `T` isn't `U` or there is no such kernel.
+fn transpose_array_as<T: 'static, U: Pod, const R: usize, const C: usize>(
+    m: &[[T; C]; R],
+    out: &mut [[T; R]; C],
+) -> bool {
+    match (
+        (m as &dyn Any).downcast_ref::<[[U; C]; R]>(),
+        (out as &mut dyn Any).downcast_mut::<[[U; R]; C]>(),
+    ) {
+        (Some(m), Some(out)) => {
+            R == C && transpose_square_batch(m.as_flattened(), out.as_flattened_mut(), R)
+        }
+        _ => false,
+    }
+}
+
+/// Performs transposition of a compile time sized matrix
+///
+/// Square matrices of primitive numeric types having an in-register kernel,
+/// e.g. 4x4 of `f32`, 8x8 of `u8` or 8x8 of `u16`, are transposed with it,
+/// others are copied element by element.
+///
+/// # Arguments
+///
+/// * `m`: Matrix of `R` rows and `C` columns
+///
+/// returns: Matrix of `C` rows and `R` columns
+///
+pub fn transpose_array<T: Copy + 'static, const R: usize, const C: usize>(
+    m: &[[T; C]; R],
+) -> [[T; R]; C] {
+    if let Some(&v) = m.as_flattened().first() {
+        let mut out = [[v; R]; C];
+        if transpose_array_as::<T, u8, R, C>(m, &mut out)
+            || transpose_array_as::<T, i8, R, C>(m, &mut out)
+            || transpose_array_as::<T, u16, R, C>(m, &mut out)
+            || transpose_array_as::<T, i16, R, C>(m, &mut out)
+            || transpose_array_as::<T, u32, R, C>(m, &mut out)
+            || transpose_array_as::<T, i32, R, C>(m, &mut out)
+            || transpose_array_as::<T, f32, R, C>(m, &mut out)
+            || transpose_array_as::<T, u64, R, C>(m, &mut out)
+            || transpose_array_as::<T, i64, R, C>(m, &mut out)
+            || transpose_array_as::<T, f64, R, C>(m, &mut out)
+        {
+            return out;
+        }
+    }
+    core::array::from_fn(|x| core::array::from_fn(|y| m[y][x]))
+}
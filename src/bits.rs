@@ -0,0 +1,587 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::{FlipMode, FlopMode, TransposeError};
+
+/// Declares number of bits per pixel of packed image
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
+pub enum PackedDepth {
+    /// 1 bit per pixel, 8 pixels in a byte
+    One,
+    /// 2 bits per pixel, 4 pixels in a byte
+    Two,
+    /// 4 bits per pixel, 2 pixels in a byte
+    Four,
+}
+
+impl PackedDepth {
+    #[inline]
+    fn bits(self) -> usize {
+        match self {
+            PackedDepth::One => 1,
+            PackedDepth::Two => 2,
+            PackedDepth::Four => 4,
+        }
+    }
+
+    #[inline]
+    fn pixels_per_byte(self) -> usize {
+        8 / self.bits()
+    }
+
+    /// Bytes in a row of `width` pixels
+    #[inline]
+    fn row_bytes(self, width: usize) -> usize {
+        (width * self.bits()).div_ceil(8)
+    }
+
+    /// Reverses order of pixels in a byte
+    #[inline]
+    fn reverse_pixels(self, byte: u8) -> u8 {
+        match self {
+            PackedDepth::One => byte.reverse_bits(),
+            PackedDepth::Two => {
+                ((byte & 0x03) << 6) | ((byte & 0x0C) << 2) | ((byte & 0x30) >> 2) | (byte >> 6)
+            }
+            PackedDepth::Four => byte.rotate_left(4),
+        }
+    }
+
+    /// Transposes square block of pixels, byte `i` counting from the most significant is row `i`,
+    /// the most significant pixel of a byte is column 0.
+    #[inline]
+    fn transpose_block(self, x: u64) -> u64 {
+        match self {
+            PackedDepth::One => {
+                let mut x = x;
+                let t = (x ^ (x >> 7)) & 0x00AA_00AA_00AA_00AA;
+                x ^= t ^ (t << 7);
+                let t = (x ^ (x >> 14)) & 0x0000_CCCC_0000_CCCC;
+                x ^= t ^ (t << 14);
+                let t = (x ^ (x >> 28)) & 0x0000_0000_F0F0_F0F0;
+                x ^= t ^ (t << 28);
+                x
+            }
+            PackedDepth::Two => {
+                let mut x = (x >> 32) as u32;
+                let t = (x ^ (x >> 6)) & 0x00CC_00CC;
+                x ^= t ^ (t << 6);
+                let t = (x ^ (x >> 12)) & 0x0000_F0F0;
+                x ^= t ^ (t << 12);
+                (x as u64) << 32
+            }
+            PackedDepth::Four => {
+                let mut x = (x >> 48) as u16;
+                let t = (x ^ (x >> 4)) & 0x00F0;
+                x ^= t ^ (t << 4);
+                (x as u64) << 48
+            }
+        }
+    }
+}
+
+/// Declares order of pixels within a byte of packed image
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Default)]
+pub enum BitOrder {
+    /// The first pixel is in the most significant bits
+    #[default]
+    MsbFirst,
+    /// The first pixel is in the least significant bits
+    LsbFirst,
+}
+
+/// Packed pixels layout
+#[derive(Copy, Clone)]
+struct Packing {
+    depth: PackedDepth,
+    bit_order: BitOrder,
+}
+
+impl Packing {
+    /// Converts byte to or from MSB first pixel order
+    #[inline]
+    fn msb(self, byte: u8) -> u8 {
+        match self.bit_order {
+            BitOrder::MsbFirst => byte,
+            BitOrder::LsbFirst => self.depth.reverse_pixels(byte),
+        }
+    }
+
+    /// Mask of valid bits of the last byte of a row of `width` pixels in MSB first order
+    #[inline]
+    fn last_byte_mask(self, width: usize) -> u8 {
+        match (width * self.depth.bits()) % 8 {
+            0 => 0xff,
+            tail => 0xffu8 << (8 - tail),
+        }
+    }
+
+    fn check(
+        self,
+        length: usize,
+        stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        if length != stride * height {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if stride < self.depth.row_bytes(width) {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        Ok(())
+    }
+
+    fn transpose(
+        self,
+        input: &[u8],
+        input_stride: usize,
+        output: &mut [u8],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+    ) -> Result<(), TransposeError> {
+        self.check(input.len(), input_stride, width, height)?;
+        self.check(output.len(), output_stride, height, width)?;
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+
+        let pixels = self.depth.pixels_per_byte();
+        let output_bytes = self.depth.row_bytes(height);
+        let output_mask = self.last_byte_mask(height);
+
+        for bx in 0..self.depth.row_bytes(width) {
+            for cx in 0..output_bytes {
+                // Output column `c` holds input row `c`, or `height - 1 - c` when flipped.
+                let mut block = 0u64;
+                for k in 0..pixels {
+                    let column = cx * pixels + k;
+                    let byte = if column < height {
+                        let row = match flip_mode {
+                            FlipMode::NoFlip => column,
+                            FlipMode::Flip => height - 1 - column,
+                        };
+                        self.msb(input[row * input_stride + bx])
+                    } else {
+                        0
+                    };
+                    block |= (byte as u64) << (56 - k * 8);
+                }
+                let transposed = self.depth.transpose_block(block);
+                // Output row `r` holds input column `r` when flopped, `width - 1 - r` otherwise.
+                for i in 0..pixels.min(width - bx * pixels) {
+                    let x = bx * pixels + i;
+                    let row = match flop_mode {
+                        FlopMode::Flop => x,
+                        FlopMode::NoFlop => width - 1 - x,
+                    };
+                    let mut byte = (transposed >> (56 - i * 8)) as u8;
+                    if cx + 1 == output_bytes {
+                        byte &= output_mask;
+                    }
+                    output[row * output_stride + cx] = self.msb(byte);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Mirrors one packed row, `dst` and `src` hold exactly row bytes of `width` pixels
+    fn reverse_row(self, src: &[u8], dst: &mut [u8], width: usize) {
+        let padding = (dst.len() * 8 - width * self.depth.bits()) as u32;
+        let reversed = |i: usize| self.depth.reverse_pixels(self.msb(src[src.len() - 1 - i]));
+        for (i, dst) in dst.iter_mut().enumerate() {
+            let current = reversed(i);
+            *dst = if padding == 0 {
+                current
+            } else {
+                let next = if i + 1 < src.len() {
+                    reversed(i + 1)
+                } else {
+                    0
+                };
+                (current << padding) | (next >> (8 - padding))
+            };
+        }
+        if let Some(last) = dst.last_mut() {
+            *last &= self.last_byte_mask(width);
+        }
+        for dst in dst.iter_mut() {
+            *dst = self.msb(*dst);
+        }
+    }
+
+    fn flip(
+        self,
+        input: &[u8],
+        input_stride: usize,
+        output: &mut [u8],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        self.check(input.len(), input_stride, width, height)?;
+        self.check(output.len(), output_stride, width, height)?;
+        if width == 0 {
+            return Ok(());
+        }
+        let row_bytes = self.depth.row_bytes(width);
+        for (dst, src) in output
+            .chunks_exact_mut(output_stride)
+            .zip(input.chunks_exact(input_stride))
+        {
+            self.reverse_row(&src[..row_bytes], &mut dst[..row_bytes], width);
+        }
+        Ok(())
+    }
+
+    fn flop(
+        self,
+        input: &[u8],
+        input_stride: usize,
+        output: &mut [u8],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        self.check(input.len(), input_stride, width, height)?;
+        self.check(output.len(), output_stride, width, height)?;
+        if width == 0 {
+            return Ok(());
+        }
+        let row_bytes = self.depth.row_bytes(width);
+        let mask = self.msb(self.last_byte_mask(width));
+        for (dst, src) in output
+            .chunks_exact_mut(output_stride)
+            .zip(input.chunks_exact(input_stride).rev())
+        {
+            let dst = &mut dst[..row_bytes];
+            dst.copy_from_slice(&src[..row_bytes]);
+            dst[row_bytes - 1] &= mask;
+        }
+        Ok(())
+    }
+
+    fn rotate180(
+        self,
+        input: &[u8],
+        input_stride: usize,
+        output: &mut [u8],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        self.check(input.len(), input_stride, width, height)?;
+        self.check(output.len(), output_stride, width, height)?;
+        if width == 0 {
+            return Ok(());
+        }
+        let row_bytes = self.depth.row_bytes(width);
+        for (dst, src) in output
+            .chunks_exact_mut(output_stride)
+            .zip(input.chunks_exact(input_stride).rev())
+        {
+            self.reverse_row(&src[..row_bytes], &mut dst[..row_bytes], width);
+        }
+        Ok(())
+    }
+}
+
+const BITMAP: Packing = Packing {
+    depth: PackedDepth::One,
+    bit_order: BitOrder::MsbFirst,
+};
+
+/// Performs packed 1 bit per pixel bitmap transposition
+///
+/// Pixels are packed MSB first, strides are in bytes.
+/// Padding bits of output rows are cleared.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride in bytes
+/// * `output`: Output data
+/// * `output_stride`: Output data stride in bytes
+/// * `width`: Bitmap width in pixels
+/// * `height`: Bitmap height in pixels
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_bits(
+    input: &[u8],
+    input_stride: usize,
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    BITMAP.transpose(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Performs packed 1 bit per pixel bitmap flipping
+///
+/// Pixels are packed MSB first, strides are in bytes.
+/// Padding bits of output rows are cleared.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride in bytes
+/// * `output`: Output data
+/// * `output_stride`: Output data stride in bytes
+/// * `width`: Bitmap width in pixels
+/// * `height`: Bitmap height in pixels
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flip_bits(
+    input: &[u8],
+    input_stride: usize,
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    BITMAP.flip(input, input_stride, output, output_stride, width, height)
+}
+
+/// Performs packed 1 bit per pixel bitmap flopping
+///
+/// Pixels are packed MSB first, strides are in bytes.
+/// Padding bits of output rows are cleared.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride in bytes
+/// * `output`: Output data
+/// * `output_stride`: Output data stride in bytes
+/// * `width`: Bitmap width in pixels
+/// * `height`: Bitmap height in pixels
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flop_bits(
+    input: &[u8],
+    input_stride: usize,
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    BITMAP.flop(input, input_stride, output, output_stride, width, height)
+}
+
+/// Performs packed 1 bit per pixel bitmap rotation by 180 degrees
+///
+/// Pixels are packed MSB first, strides are in bytes.
+/// Padding bits of output rows are cleared.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride in bytes
+/// * `output`: Output data
+/// * `output_stride`: Output data stride in bytes
+/// * `width`: Bitmap width in pixels
+/// * `height`: Bitmap height in pixels
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate180_bits(
+    input: &[u8],
+    input_stride: usize,
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    BITMAP.rotate180(input, input_stride, output, output_stride, width, height)
+}
+
+/// Performs packed image transposition
+///
+/// Rows are packed according to `depth` and `bit_order`, strides are in bytes.
+/// Padding bits of output rows are cleared.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride in bytes
+/// * `output`: Output data
+/// * `output_stride`: Output data stride in bytes
+/// * `width`: Image width in pixels
+/// * `height`: Image height in pixels
+/// * `depth`: see [PackedDepth]
+/// * `bit_order`: see [BitOrder]
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_packed(
+    input: &[u8],
+    input_stride: usize,
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    depth: PackedDepth,
+    bit_order: BitOrder,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    Packing { depth, bit_order }.transpose(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Performs packed image flipping
+///
+/// Rows are packed according to `depth` and `bit_order`, strides are in bytes.
+/// Padding bits of output rows are cleared.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride in bytes
+/// * `output`: Output data
+/// * `output_stride`: Output data stride in bytes
+/// * `width`: Image width in pixels
+/// * `height`: Image height in pixels
+/// * `depth`: see [PackedDepth]
+/// * `bit_order`: see [BitOrder]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flip_packed(
+    input: &[u8],
+    input_stride: usize,
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    depth: PackedDepth,
+    bit_order: BitOrder,
+) -> Result<(), TransposeError> {
+    Packing { depth, bit_order }.flip(input, input_stride, output, output_stride, width, height)
+}
+
+/// Performs packed image flopping
+///
+/// Rows are packed according to `depth` and `bit_order`, strides are in bytes.
+/// Padding bits of output rows are cleared.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride in bytes
+/// * `output`: Output data
+/// * `output_stride`: Output data stride in bytes
+/// * `width`: Image width in pixels
+/// * `height`: Image height in pixels
+/// * `depth`: see [PackedDepth]
+/// * `bit_order`: see [BitOrder]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flop_packed(
+    input: &[u8],
+    input_stride: usize,
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    depth: PackedDepth,
+    bit_order: BitOrder,
+) -> Result<(), TransposeError> {
+    Packing { depth, bit_order }.flop(input, input_stride, output, output_stride, width, height)
+}
+
+/// Performs packed image rotation by 180 degrees
+///
+/// Rows are packed according to `depth` and `bit_order`, strides are in bytes.
+/// Padding bits of output rows are cleared.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride in bytes
+/// * `output`: Output data
+/// * `output_stride`: Output data stride in bytes
+/// * `width`: Image width in pixels
+/// * `height`: Image height in pixels
+/// * `depth`: see [PackedDepth]
+/// * `bit_order`: see [BitOrder]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate180_packed(
+    input: &[u8],
+    input_stride: usize,
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    depth: PackedDepth,
+    bit_order: BitOrder,
+) -> Result<(), TransposeError> {
+    Packing { depth, bit_order }.rotate180(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+    )
+}
@@ -208,7 +208,7 @@ pub(crate) fn transpose_cbcr8_chunked(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Result<(), TransposeError> {
-    if input.len() != input_stride * height {
+    if !crate::utils::fits_strided(input.len(), input_stride, height, width * 2) {
         return Err(TransposeError::MismatchDimensions);
     }
     if output.len() != output_stride * width {
@@ -236,13 +236,32 @@ pub(crate) fn transpose_cbcr8_chunked(
         Ok(())
     }
 
+    #[cfg(static_ssse3)]
+    {
+        unsafe {
+            crate::utils::dispatch_flip_flop!(
+                flip_mode,
+                flop_mode,
+                transpose_cbcr8_impl_ssse3,
+                input,
+                input_stride,
+                output,
+                output_stride,
+                width,
+                height
+            );
+        }
+        Ok(())
+    }
+
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
         feature = "unsafe",
-        any(feature = "sse", feature = "avx")
+        feature = "sse",
+        not(static_ssse3)
     ))]
     {
-        if std::arch::is_x86_feature_detected!("ssse3") {
+        if crate::utils::has_x86_feature!("ssse3") {
             let executor: unsafe fn(&[u8], usize, &mut [u8], usize, usize, usize) = match flip_mode
             {
                 FlipMode::NoFlip => match flop_mode {
@@ -259,10 +278,10 @@ pub(crate) fn transpose_cbcr8_chunked(
             return Ok(());
         }
     }
-    #[cfg(not(all(target_arch = "aarch64", feature = "unsafe", feature = "neon")))]
+    #[cfg(not(static_dispatch_ssse3))]
     {
-        use crate::transpose_arbitrary_group::transpose_arbitrary_grouped;
-        transpose_arbitrary_grouped::<u8, 2>(
+        use crate::transpose_arbitrary_group::transpose_arbitrary_grouped_view;
+        transpose_arbitrary_grouped_view::<u8, 2>(
             input,
             input_stride,
             output,
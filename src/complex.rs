@@ -0,0 +1,316 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::float32_cbcr_invoker::execute_f32_cbcr;
+use crate::float64_cbcr_invoker::execute_f64_cbcr;
+use crate::tile::TransposeFn;
+use crate::{FlipMode, FlopMode, TransposeError};
+use alloc::vec;
+use core::ops::Neg;
+use num_complex::Complex;
+
+/// Conjugate transposition is done in tiles of this many output rows and columns,
+/// each tile is transposed into a scratch buffer and written to the output
+/// already conjugated.
+const TILE_ROWS: usize = 32;
+const TILE_COLS: usize = 128;
+
+fn execute_complex32(
+    input: &[Complex<f32>],
+    input_stride: usize,
+    output: &mut [Complex<f32>],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    execute_f32_cbcr(
+        bytemuck::cast_slice(input),
+        input_stride * 2,
+        bytemuck::cast_slice_mut(output),
+        output_stride * 2,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+fn execute_complex64(
+    input: &[Complex<f64>],
+    input_stride: usize,
+    output: &mut [Complex<f64>],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    execute_f64_cbcr(
+        bytemuck::cast_slice(input),
+        input_stride * 2,
+        bytemuck::cast_slice_mut(output),
+        output_stride * 2,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+fn conjugate_transpose_impl<T: Copy + Default + Neg<Output = T>>(
+    input: &[Complex<T>],
+    input_stride: usize,
+    output: &mut [Complex<T>],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+    executor: TransposeFn<Complex<T>>,
+) -> Result<(), TransposeError> {
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if output.len() != output_stride * width {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if input_stride < width || output_stride < height {
+        return Err(TransposeError::MismatchDimensions);
+    }
+
+    let mut scratch = vec![Complex::<T>::default(); TILE_ROWS.min(width) * TILE_COLS.min(height)];
+
+    for dst_y in (0..width).step_by(TILE_ROWS) {
+        let rows = TILE_ROWS.min(width - dst_y);
+        let src_x = match flop_mode {
+            FlopMode::Flop => dst_y,
+            FlopMode::NoFlop => width - dst_y - rows,
+        };
+        for dst_x in (0..height).step_by(TILE_COLS) {
+            let cols = TILE_COLS.min(height - dst_x);
+            let src_y = match flip_mode {
+                FlipMode::NoFlip => dst_x,
+                FlipMode::Flip => height - dst_x - cols,
+            };
+
+            let src = &input[src_y * input_stride + src_x..];
+            let src = &src[..(cols - 1) * input_stride + rows];
+            let tile = &mut scratch[..rows * cols];
+            executor(
+                src,
+                input_stride,
+                tile,
+                cols,
+                rows,
+                cols,
+                flip_mode,
+                flop_mode,
+            )?;
+
+            for (dst, src) in output[dst_y * output_stride..]
+                .chunks_mut(output_stride)
+                .zip(tile.chunks_exact(cols))
+            {
+                for (dst, src) in dst[dst_x..dst_x + cols].iter_mut().zip(src.iter()) {
+                    *dst = Complex::new(src.re, -src.im);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Performs complex matrix transposition
+///
+/// Complex values are transposed with kernels of two channel f32 images.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Matrix width
+/// * `height`: Matrix height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_complex32(
+    input: &[Complex<f32>],
+    input_stride: usize,
+    output: &mut [Complex<f32>],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    execute_complex32(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Performs complex matrix transposition
+///
+/// Complex values are transposed with kernels of two channel f64 images.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Matrix width
+/// * `height`: Matrix height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_complex64(
+    input: &[Complex<f64>],
+    input_stride: usize,
+    output: &mut [Complex<f64>],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    execute_complex64(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Performs complex matrix conjugate transposition
+///
+/// Output is transposed tile by tile into a small scratch buffer and conjugated
+/// while being copied out, so every output value is written only once.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Matrix width
+/// * `height`: Matrix height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn conjugate_transpose_complex32(
+    input: &[Complex<f32>],
+    input_stride: usize,
+    output: &mut [Complex<f32>],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    conjugate_transpose_impl(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        execute_complex32,
+    )
+}
+
+/// Performs complex matrix conjugate transposition
+///
+/// Output is transposed tile by tile into a small scratch buffer and conjugated
+/// while being copied out, so every output value is written only once.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Matrix width
+/// * `height`: Matrix height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn conjugate_transpose_complex64(
+    input: &[Complex<f64>],
+    input_stride: usize,
+    output: &mut [Complex<f64>],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    conjugate_transpose_impl(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        execute_complex64,
+    )
+}
@@ -27,6 +27,8 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 use crate::TransposeError;
+#[allow(unused_imports)]
+use alloc::boxed::Box;
 use bytemuck::{AnyBitPattern, NoUninit, Pod};
 
 trait Flipper<V: Copy> {
@@ -72,11 +74,13 @@ macro_rules! reverse_copy {
     };
 }
 
+#[cfg(not(static_reverse))]
 #[derive(Debug, Copy, Clone, Default)]
 struct CommonGroupedFlipper<V: Copy + Pod + NoUninit + AnyBitPattern, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
+#[cfg(not(static_reverse))]
 impl<V: Copy + Pod + NoUninit + AnyBitPattern, const N: usize> Flipper<V>
     for CommonGroupedFlipper<V, N>
 where
@@ -95,10 +99,14 @@ where
     }
 }
 
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_reverse)
+))]
 #[derive(Debug, Copy, Clone, Default)]
 struct SSSE3GroupedFlipper<V: Copy, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 macro_rules! define_flipper_grouped_x86 {
@@ -180,20 +188,34 @@ macro_rules! define_flipper_grouped_aarch64 {
     };
 }
 
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_reverse)
+))]
 define_flipper_grouped_x86!(SSSE3GroupedFlipper, "ssse3");
 
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_reverse)
+))]
 #[derive(Debug, Copy, Clone, Default)]
 struct Sse41GroupedFlipper<V: Copy, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_reverse)
+))]
 define_flipper_grouped_x86!(Sse41GroupedFlipper, "sse4.1");
 
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
 #[derive(Debug, Copy, Clone, Default)]
 struct Avx2GroupedFlipper<V: Copy, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 define_flipper_grouped_x86!(Avx2GroupedFlipper, "avx2");
@@ -201,14 +223,14 @@ define_flipper_grouped_x86!(Avx2GroupedFlipper, "avx2");
 #[cfg(all(target_arch = "aarch64", feature = "unsafe"))]
 #[derive(Debug, Copy, Clone, Default)]
 struct SveGroupedFlipper<V: Copy + Pod + NoUninit + AnyBitPattern, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 define_flipper_grouped_aarch64!(SveGroupedFlipper, "sve2");
 
 #[derive(Debug, Copy, Clone, Default)]
 struct FlipperGroupedFactory<V: Copy + Pod + NoUninit + AnyBitPattern, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 impl<V: Copy + 'static + Copy + Pod + NoUninit + AnyBitPattern, const N: usize>
@@ -217,23 +239,37 @@ where
     V: Default,
     [V; N]: Pod,
 {
-    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+    #[cfg(x86_avx2)]
+    fn make_flipper(&self) -> Avx2GroupedFlipper<V, N> {
+        Avx2GroupedFlipper::<V, N>::default()
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        not(x86_avx2)
+    ))]
     fn make_flipper(&self) -> Box<dyn Flipper<V>> {
-        if std::arch::is_x86_feature_detected!("avx2") {
+        if crate::utils::has_x86_feature!("avx2") {
             return Box::new(Avx2GroupedFlipper::<V, N>::default());
         }
-        if std::arch::is_x86_feature_detected!("sse4.1") {
+        if crate::utils::has_x86_feature!("sse4.1") {
             return Box::new(Sse41GroupedFlipper::<V, N>::default());
         }
-        if std::arch::is_x86_feature_detected!("ssse3") {
+        if crate::utils::has_x86_feature!("ssse3") {
             return Box::new(SSSE3GroupedFlipper::<V, N>::default());
         }
         Box::new(CommonGroupedFlipper::<V, N>::default())
     }
 
-    #[cfg(all(target_arch = "aarch64", feature = "unsafe"))]
+    #[cfg(aarch64_sve2)]
+    fn make_flipper(&self) -> SveGroupedFlipper<V, N> {
+        SveGroupedFlipper::<V, N>::default()
+    }
+
+    #[cfg(all(target_arch = "aarch64", feature = "unsafe", not(aarch64_sve2)))]
     fn make_flipper(&self) -> Box<dyn Flipper<V>> {
-        if std::arch::is_aarch64_feature_detected!("sve2") {
+        if crate::utils::has_aarch64_feature!("sve2") {
             return Box::new(SveGroupedFlipper::<V, N>::default());
         }
         Box::new(CommonGroupedFlipper::<V, N>::default())
@@ -243,8 +279,8 @@ where
         all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"),
         all(target_arch = "aarch64", feature = "unsafe")
     )))]
-    fn make_flipper(&self) -> Box<dyn Flipper<V>> {
-        Box::new(CommonGroupedFlipper::<V, N>::default())
+    fn make_flipper(&self) -> CommonGroupedFlipper<V, N> {
+        CommonGroupedFlipper::<V, N>::default()
     }
 }
 
@@ -281,6 +317,7 @@ macro_rules! define_flipper_aarch64 {
     };
 }
 
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
 macro_rules! define_flipper_x86 {
     ($flipper_type: ident, $feature: literal) => {
         #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
@@ -314,11 +351,13 @@ macro_rules! define_flipper_x86 {
     };
 }
 
+#[cfg(not(static_flip))]
 #[derive(Debug, Copy, Clone, Default)]
 struct CommonFlipper<V: Copy + Default> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
+#[cfg(not(static_flip))]
 impl<V: Copy + Default> Flipper<V> for CommonFlipper<V> {
     #[inline(always)]
     fn flip(
@@ -335,39 +374,66 @@ impl<V: Copy + Default> Flipper<V> for CommonFlipper<V> {
 
 #[derive(Debug, Copy, Clone, Default)]
 struct FlipperFactory<V: Copy + Default + 'static> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 #[cfg(all(target_arch = "aarch64", feature = "unsafe"))]
 #[derive(Debug, Copy, Clone, Default)]
 struct SveFlipper<V: Copy + Default + 'static> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 define_flipper_aarch64!(SveFlipper, "sve2");
 
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(x86_avx512bw)
+))]
 #[derive(Debug, Copy, Clone, Default)]
 struct Avx2Flipper<V: Copy> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(x86_avx512bw)
+))]
 define_flipper_x86!(Avx2Flipper, "avx2");
 
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_flip)
+))]
 #[derive(Debug, Copy, Clone, Default)]
 struct Sse41Flipper<V: Copy> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_flip)
+))]
 define_flipper_x86!(Sse41Flipper, "sse4.1");
 
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_flip)
+))]
 #[derive(Debug, Copy, Clone, Default)]
 struct SSSE3Flipper<V: Copy> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_flip)
+))]
 define_flipper_x86!(SSSE3Flipper, "ssse3");
 
 #[cfg(all(
@@ -376,34 +442,56 @@ define_flipper_x86!(SSSE3Flipper, "ssse3");
 ))]
 #[derive(Debug, Copy, Clone, Default)]
 struct Avx512Flipper<V: Copy> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
-#[cfg(feature = "nightly_avx512")]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "nightly_avx512"
+))]
 define_flipper_x86!(Avx512Flipper, "avx512bw");
 
 impl<V: Copy + Default + 'static> FlipperFactory<V> {
-    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+    #[cfg(x86_avx512bw)]
+    fn make_flipper(&self) -> Avx512Flipper<V> {
+        Avx512Flipper::<V>::default()
+    }
+
+    #[cfg(all(x86_avx2, not(feature = "nightly_avx512")))]
+    fn make_flipper(&self) -> Avx2Flipper<V> {
+        Avx2Flipper::<V>::default()
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        not(static_flip)
+    ))]
     fn make_flipper(&self) -> Box<dyn Flipper<V>> {
         #[cfg(feature = "nightly_avx512")]
-        if std::arch::is_x86_feature_detected!("avx512bw") {
+        if crate::utils::has_x86_feature!("avx512bw") {
             return Box::new(Avx512Flipper::<V>::default());
         }
-        if std::arch::is_x86_feature_detected!("avx2") {
+        if crate::utils::has_x86_feature!("avx2") {
             return Box::new(Avx2Flipper::<V>::default());
         }
-        if std::arch::is_x86_feature_detected!("sse4.1") {
+        if crate::utils::has_x86_feature!("sse4.1") {
             return Box::new(Sse41Flipper::<V>::default());
         }
-        if std::arch::is_x86_feature_detected!("ssse3") {
+        if crate::utils::has_x86_feature!("ssse3") {
             return Box::new(SSSE3Flipper::<V>::default());
         }
         Box::new(CommonFlipper::<V>::default())
     }
 
-    #[cfg(all(target_arch = "aarch64", feature = "unsafe"))]
+    #[cfg(aarch64_sve2)]
+    fn make_flipper(&self) -> SveFlipper<V> {
+        SveFlipper::<V>::default()
+    }
+
+    #[cfg(all(target_arch = "aarch64", feature = "unsafe", not(aarch64_sve2)))]
     fn make_flipper(&self) -> Box<dyn Flipper<V>> {
-        if std::arch::is_aarch64_feature_detected!("sve2") {
+        if crate::utils::has_aarch64_feature!("sve2") {
             return Box::new(SveFlipper::<V>::default());
         }
         Box::new(CommonFlipper::<V>::default())
@@ -413,8 +501,8 @@ impl<V: Copy + Default + 'static> FlipperFactory<V> {
         all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"),
         all(target_arch = "aarch64", feature = "unsafe")
     )))]
-    fn make_flipper(&self) -> Box<dyn Flipper<V>> {
-        Box::new(CommonFlipper::<V>::default())
+    fn make_flipper(&self) -> CommonFlipper<V> {
+        CommonFlipper::<V>::default()
     }
 }
 
@@ -459,6 +547,43 @@ pub fn flip_arbitrary<V: Copy + Default + 'static>(
     Ok(())
 }
 
+/// Performs flipping of `Pod` types
+///
+/// Types of 1, 2, 4, 8 or 16 bytes are processed as unsigned integers or `u64` pairs of the same size,
+/// others are handled as in [flip_arbitrary].
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Array width
+/// * `height`: Array height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flip_pod<V: Pod + Default>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    crate::utils::dispatch_pod_size!(
+        V,
+        flip_arbitrary,
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height
+    )
+    .unwrap_or_else(|| flip_arbitrary(input, input_stride, output, output_stride, width, height))
+}
+
 /// Performs arbitrary flipping
 ///
 /// # Arguments
@@ -790,3 +915,27 @@ pub fn flip_rgba_f32(
 ) -> Result<(), TransposeError> {
     flip_arbitrary_image::<f32, 4>(input, input_stride, output, output_stride, width, height)
 }
+
+/// Performs plane image flipping
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Array width
+/// * `height`: Array height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flip_plane_f64(
+    input: &[f64],
+    input_stride: usize,
+    output: &mut [f64],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    flip_arbitrary(input, input_stride, output, output_stride, width, height)
+}
@@ -29,9 +29,11 @@
 use crate::float32_invoker::TransposeExecutor;
 #[allow(unused_imports)]
 use crate::rgba8::*;
-use crate::transpose_arbitrary_group::transpose_arbitrary_grouped;
+use crate::transpose_arbitrary_group::transpose_arbitrary_grouped_view;
 use crate::{FlipMode, FlopMode, TransposeError};
-use std::marker::PhantomData;
+#[cfg(not(static_dispatch))]
+use alloc::boxed::Box;
+use core::marker::PhantomData;
 
 #[allow(dead_code)]
 #[derive(Copy, Clone)]
@@ -52,7 +54,7 @@ impl<F: Copy> TransposeExecutor<F> for DefaultCbCrExecutor<F> {
         width: usize,
         height: usize,
     ) -> Result<(), TransposeError> {
-        transpose_arbitrary_grouped::<F, 2>(
+        transpose_arbitrary_grouped_view::<F, 2>(
             input,
             input_stride,
             output,
@@ -115,10 +117,18 @@ impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f32>
     }
 }
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 struct TransposeBlockAvx4x4F32x2<const FLOP: bool, const FLIP: bool> {}
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f32>
     for TransposeBlockAvx4x4F32x2<FLOP, FLIP>
 {
@@ -133,27 +143,11 @@ impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f32>
 #[derive(Copy, Clone, Default)]
 struct NeonDefaultExecutor<const FLOP: bool, const FLIP: bool> {}
 
-#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
-pub(crate) fn make_neon_default_executor(
-    flip_mode: FlipMode,
-    flop_mode: FlopMode,
-) -> Box<dyn TransposeExecutor<f32>> {
-    match flip_mode {
-        FlipMode::NoFlip => match flop_mode {
-            FlopMode::NoFlop => Box::new(NeonDefaultExecutor::<false, false>::default()),
-            FlopMode::Flop => Box::new(NeonDefaultExecutor::<true, false>::default()),
-        },
-        FlipMode::Flip => match flop_mode {
-            FlopMode::NoFlop => Box::new(NeonDefaultExecutor::<false, true>::default()),
-            FlopMode::Flop => Box::new(NeonDefaultExecutor::<true, true>::default()),
-        },
-    }
-}
-
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
     feature = "unsafe",
-    feature = "sse"
+    feature = "sse",
+    not(static_avx2)
 ))]
 #[derive(Copy, Clone, Default)]
 struct Ssse3DefaultExecutor<const FLOP: bool, const FLIP: bool> {}
@@ -161,7 +155,8 @@ struct Ssse3DefaultExecutor<const FLOP: bool, const FLIP: bool> {}
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
     feature = "unsafe",
-    feature = "sse"
+    feature = "sse",
+    not(static_avx2)
 ))]
 impl<const FLOP: bool, const FLIP: bool> Ssse3DefaultExecutor<FLOP, FLIP> {
     #[target_feature(enable = "ssse3")]
@@ -174,7 +169,7 @@ impl<const FLOP: bool, const FLIP: bool> Ssse3DefaultExecutor<FLOP, FLIP> {
         width: usize,
         height: usize,
     ) -> Result<(), TransposeError> {
-        if input.len() != input_stride * height {
+        if !crate::utils::fits_strided(input.len(), input_stride, height, width * 2) {
             return Err(TransposeError::MismatchDimensions);
         }
         if output.len() != output_stride * width {
@@ -217,7 +212,8 @@ impl<const FLOP: bool, const FLIP: bool> Ssse3DefaultExecutor<FLOP, FLIP> {
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
     feature = "unsafe",
-    feature = "sse"
+    feature = "sse",
+    not(static_avx2)
 ))]
 impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f32>
     for Ssse3DefaultExecutor<FLOP, FLIP>
@@ -243,7 +239,11 @@ impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f32>
 #[derive(Copy, Clone, Default)]
 struct AvxDefaultExecutor<const FLOP: bool, const FLIP: bool> {}
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 impl<const FLOP: bool, const FLIP: bool> AvxDefaultExecutor<FLOP, FLIP> {
     #[target_feature(enable = "avx2")]
     unsafe fn execute_impl(
@@ -255,7 +255,7 @@ impl<const FLOP: bool, const FLIP: bool> AvxDefaultExecutor<FLOP, FLIP> {
         width: usize,
         height: usize,
     ) -> Result<(), TransposeError> {
-        if input.len() != input_stride * height {
+        if !crate::utils::fits_strided(input.len(), input_stride, height, width * 2) {
             return Err(TransposeError::MismatchDimensions);
         }
         if output.len() != output_stride * width {
@@ -306,7 +306,11 @@ impl<const FLOP: bool, const FLIP: bool> AvxDefaultExecutor<FLOP, FLIP> {
     }
 }
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f32> for AvxDefaultExecutor<FLOP, FLIP> {
     fn execute(
         &self,
@@ -334,7 +338,7 @@ impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f32>
         width: usize,
         height: usize,
     ) -> Result<(), TransposeError> {
-        if input.len() != input_stride * height {
+        if !crate::utils::fits_strided(input.len(), input_stride, height, width * 2) {
             return Err(TransposeError::MismatchDimensions);
         }
         if output.len() != output_stride * width {
@@ -386,15 +390,18 @@ impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f32>
 }
 
 #[cfg(all(
-    any(target_arch = "x86", target_arch = "x86_64"),
-    feature = "unsafe",
-    feature = "sse"
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "sse"
+    ),
+    not(static_dispatch)
 ))]
 fn make_ssse3_default_executor(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Option<Box<dyn TransposeExecutor<f32>>> {
-    if std::arch::is_x86_feature_detected!("ssse3") {
+    if crate::utils::has_x86_feature!("ssse3") {
         return Some(match flip_mode {
             FlipMode::NoFlip => match flop_mode {
                 FlopMode::NoFlop => Box::new(Ssse3DefaultExecutor::<false, false>::default()),
@@ -409,12 +416,19 @@ fn make_ssse3_default_executor(
     None
 }
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "avx"
+    ),
+    not(static_dispatch)
+))]
 fn make_avx2_default_executor(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Option<Box<dyn TransposeExecutor<f32>>> {
-    if std::arch::is_x86_feature_detected!("avx2") {
+    if crate::utils::has_x86_feature!("avx2") {
         return Some(match flip_mode {
             FlipMode::NoFlip => match flop_mode {
                 FlopMode::NoFlop => Box::new(AvxDefaultExecutor::<false, false>::default()),
@@ -429,13 +443,18 @@ fn make_avx2_default_executor(
     None
 }
 
+#[cfg(not(static_dispatch))]
 pub(crate) fn prepare_f32_cbcr_executor(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Box<dyn TransposeExecutor<f32>> {
-    #[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "avx"
+    ))]
     {
-        if std::arch::is_x86_feature_detected!("avx2") {
+        if crate::utils::has_x86_feature!("avx2") {
             if let Some(executor) = make_avx2_default_executor(flip_mode, flop_mode) {
                 return executor;
             }
@@ -447,22 +466,92 @@ pub(crate) fn prepare_f32_cbcr_executor(
         feature = "sse"
     ))]
     {
-        if std::arch::is_x86_feature_detected!("ssse3") {
+        if crate::utils::has_x86_feature!("ssse3") {
             if let Some(executor) = make_ssse3_default_executor(flip_mode, flop_mode) {
                 return executor;
             }
         }
     }
+    Box::new(DefaultCbCrExecutor {
+        flip_mode,
+        flop_mode,
+        _phantom: PhantomData,
+    })
+}
+
+#[cfg(static_dispatch)]
+fn execute_static<const FLOP: bool, const FLIP: bool>(
+    input: &[f32],
+    input_stride: usize,
+    output: &mut [f32],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
     #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
     {
-        make_neon_default_executor(flip_mode, flop_mode)
+        NeonDefaultExecutor::<FLOP, FLIP>::default().execute(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+        )
     }
-    #[cfg(not(all(target_arch = "aarch64", feature = "unsafe", feature = "neon")))]
+    #[cfg(static_avx2)]
     {
-        Box::new(DefaultCbCrExecutor {
+        AvxDefaultExecutor::<FLOP, FLIP>::default().execute(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+        )
+    }
+    #[cfg(all(static_ssse3, not(feature = "avx")))]
+    {
+        Ssse3DefaultExecutor::<FLOP, FLIP>::default().execute(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+        )
+    }
+}
+
+/// Executes f32_cbcr transposition, kernel is selected at compile time when
+/// target features allow it, otherwise at runtime.
+pub(crate) fn execute_f32_cbcr(
+    input: &[f32],
+    input_stride: usize,
+    output: &mut [f32],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    #[cfg(static_dispatch)]
+    {
+        crate::utils::dispatch_flip_flop!(
             flip_mode,
             flop_mode,
-            _phantom: PhantomData,
-        })
+            execute_static,
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height
+        )
+    }
+    #[cfg(not(static_dispatch))]
+    {
+        let executor = prepare_f32_cbcr_executor(flip_mode, flop_mode);
+        executor.execute(input, input_stride, output, output_stride, width, height)
     }
 }
@@ -28,8 +28,11 @@
  */
 #[allow(unused_imports)]
 use crate::rgba8::*;
-use crate::{transpose_arbitrary, FlipMode, FlopMode, TransposeError};
-use std::marker::PhantomData;
+use crate::transpose_arbitrary::transpose_arbitrary_view;
+use crate::{FlipMode, FlopMode, TransposeError};
+#[cfg(not(static_dispatch))]
+use alloc::boxed::Box;
+use core::marker::PhantomData;
 
 pub(crate) trait TransposeExecutor<F> {
     fn execute(
@@ -45,10 +48,10 @@ pub(crate) trait TransposeExecutor<F> {
 
 #[allow(dead_code)]
 #[derive(Copy, Clone)]
-struct DefaultExecutor<F> {
-    flip_mode: FlipMode,
-    flop_mode: FlopMode,
-    _phantom: PhantomData<F>,
+pub(crate) struct DefaultExecutor<F> {
+    pub(crate) flip_mode: FlipMode,
+    pub(crate) flop_mode: FlopMode,
+    pub(crate) _phantom: PhantomData<F>,
 }
 
 #[allow(dead_code)]
@@ -62,7 +65,7 @@ impl<F: Copy> TransposeExecutor<F> for DefaultExecutor<F> {
         width: usize,
         height: usize,
     ) -> Result<(), TransposeError> {
-        transpose_arbitrary(
+        transpose_arbitrary_view(
             input,
             input_stride,
             output,
@@ -107,23 +110,6 @@ impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f32>
 #[derive(Copy, Clone, Default)]
 struct NeonDefaultExecutor<const FLOP: bool, const FLIP: bool> {}
 
-#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
-pub(crate) fn make_neon_default_executor(
-    flip_mode: FlipMode,
-    flop_mode: FlopMode,
-) -> Box<dyn TransposeExecutor<f32>> {
-    match flip_mode {
-        FlipMode::NoFlip => match flop_mode {
-            FlopMode::NoFlop => Box::new(NeonDefaultExecutor::<false, false>::default()),
-            FlopMode::Flop => Box::new(NeonDefaultExecutor::<true, false>::default()),
-        },
-        FlipMode::Flip => match flop_mode {
-            FlopMode::NoFlop => Box::new(NeonDefaultExecutor::<false, true>::default()),
-            FlopMode::Flop => Box::new(NeonDefaultExecutor::<true, true>::default()),
-        },
-    }
-}
-
 #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
 impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f32>
     for NeonDefaultExecutor<FLOP, FLIP>
@@ -137,7 +123,7 @@ impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f32>
         width: usize,
         height: usize,
     ) -> Result<(), TransposeError> {
-        if input.len() != input_stride * height {
+        if !crate::utils::fits_strided(input.len(), input_stride, height, width) {
             return Err(TransposeError::MismatchDimensions);
         }
         if output.len() != output_stride * width {
@@ -188,10 +174,18 @@ impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f32>
     }
 }
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 struct TransposeBlockAvx28x8<const FLOP: bool, const FLIP: bool> {}
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f32> for TransposeBlockAvx28x8<FLOP, FLIP> {
     #[inline(always)]
     fn transpose_block(&self, src: &[f32], src_stride: usize, dst: &mut [f32], dst_stride: usize) {
@@ -247,21 +241,25 @@ impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f32>
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
     feature = "unsafe",
-    feature = "sse"
+    feature = "sse",
+    not(static_avx2)
 ))]
 #[derive(Copy, Clone, Default)]
 struct Ssse3DefaultExecutor<const FLOP: bool, const FLIP: bool> {}
 
 #[cfg(all(
-    any(target_arch = "x86", target_arch = "x86_64"),
-    feature = "unsafe",
-    feature = "sse"
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "sse"
+    ),
+    not(static_dispatch)
 ))]
 fn make_ssse3_default_executor(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Option<Box<dyn TransposeExecutor<f32>>> {
-    if std::arch::is_x86_feature_detected!("ssse3") {
+    if crate::utils::has_x86_feature!("ssse3") {
         return Some(match flip_mode {
             FlipMode::NoFlip => match flop_mode {
                 FlopMode::NoFlop => Box::new(Ssse3DefaultExecutor::<false, false>::default()),
@@ -279,7 +277,8 @@ fn make_ssse3_default_executor(
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
     feature = "unsafe",
-    feature = "sse"
+    feature = "sse",
+    not(static_avx2)
 ))]
 impl<const FLOP: bool, const FLIP: bool> Ssse3DefaultExecutor<FLOP, FLIP> {
     #[target_feature(enable = "ssse3")]
@@ -292,7 +291,7 @@ impl<const FLOP: bool, const FLIP: bool> Ssse3DefaultExecutor<FLOP, FLIP> {
         width: usize,
         height: usize,
     ) -> Result<(), TransposeError> {
-        if input.len() != input_stride * height {
+        if !crate::utils::fits_strided(input.len(), input_stride, height, width) {
             return Err(TransposeError::MismatchDimensions);
         }
         if output.len() != output_stride * width {
@@ -346,7 +345,8 @@ impl<const FLOP: bool, const FLIP: bool> Ssse3DefaultExecutor<FLOP, FLIP> {
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
     feature = "unsafe",
-    feature = "sse"
+    feature = "sse",
+    not(static_avx2)
 ))]
 impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f32>
     for Ssse3DefaultExecutor<FLOP, FLIP>
@@ -364,16 +364,27 @@ impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f32>
     }
 }
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 #[derive(Copy, Clone, Default)]
 struct Avx2DefaultExecutor<const FLOP: bool, const FLIP: bool> {}
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "avx"
+    ),
+    not(static_dispatch)
+))]
 pub(crate) fn make_avx2_default_executor(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Option<Box<dyn TransposeExecutor<f32>>> {
-    if std::arch::is_x86_feature_detected!("avx2") {
+    if crate::utils::has_x86_feature!("avx2") {
         return Some(match flip_mode {
             FlipMode::NoFlip => match flop_mode {
                 FlopMode::NoFlop => Box::new(Avx2DefaultExecutor::<false, false>::default()),
@@ -388,7 +399,11 @@ pub(crate) fn make_avx2_default_executor(
     None
 }
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 impl<const FLOP: bool, const FLIP: bool> Avx2DefaultExecutor<FLOP, FLIP> {
     #[target_feature(enable = "avx2")]
     unsafe fn execute_impl(
@@ -400,7 +415,7 @@ impl<const FLOP: bool, const FLIP: bool> Avx2DefaultExecutor<FLOP, FLIP> {
         width: usize,
         height: usize,
     ) -> Result<(), TransposeError> {
-        if input.len() != input_stride * height {
+        if !crate::utils::fits_strided(input.len(), input_stride, height, width) {
             return Err(TransposeError::MismatchDimensions);
         }
         if output.len() != output_stride * width {
@@ -451,7 +466,11 @@ impl<const FLOP: bool, const FLIP: bool> Avx2DefaultExecutor<FLOP, FLIP> {
     }
 }
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f32>
     for Avx2DefaultExecutor<FLOP, FLIP>
 {
@@ -468,11 +487,16 @@ impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f32>
     }
 }
 
+#[cfg(not(static_dispatch))]
 pub(crate) fn prepare_f32_plane_executor(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Box<dyn TransposeExecutor<f32>> {
-    #[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "avx"
+    ))]
     {
         if let Some(executor) = make_avx2_default_executor(flip_mode, flop_mode) {
             return executor;
@@ -488,16 +512,86 @@ pub(crate) fn prepare_f32_plane_executor(
             return executor;
         }
     }
+    Box::new(DefaultExecutor {
+        flip_mode,
+        flop_mode,
+        _phantom: PhantomData,
+    })
+}
+
+#[cfg(static_dispatch)]
+fn execute_static<const FLOP: bool, const FLIP: bool>(
+    input: &[f32],
+    input_stride: usize,
+    output: &mut [f32],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
     #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
     {
-        make_neon_default_executor(flip_mode, flop_mode)
+        NeonDefaultExecutor::<FLOP, FLIP>::default().execute(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+        )
+    }
+    #[cfg(static_avx2)]
+    {
+        Avx2DefaultExecutor::<FLOP, FLIP>::default().execute(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+        )
+    }
+    #[cfg(all(static_ssse3, not(feature = "avx")))]
+    {
+        Ssse3DefaultExecutor::<FLOP, FLIP>::default().execute(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+        )
     }
-    #[cfg(not(all(target_arch = "aarch64", feature = "unsafe", feature = "neon")))]
+}
+
+/// Executes f32_plane transposition, kernel is selected at compile time when
+/// target features allow it, otherwise at runtime.
+pub(crate) fn execute_f32_plane(
+    input: &[f32],
+    input_stride: usize,
+    output: &mut [f32],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    #[cfg(static_dispatch)]
     {
-        Box::new(DefaultExecutor {
+        crate::utils::dispatch_flip_flop!(
             flip_mode,
             flop_mode,
-            _phantom: PhantomData,
-        })
+            execute_static,
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height
+        )
+    }
+    #[cfg(not(static_dispatch))]
+    {
+        let executor = prepare_f32_plane_executor(flip_mode, flop_mode);
+        executor.execute(input, input_stride, output, output_stride, width, height)
     }
 }
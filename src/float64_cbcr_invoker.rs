@@ -0,0 +1,592 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::float32_invoker::TransposeExecutor;
+#[allow(unused_imports)]
+use crate::rgba8::*;
+#[cfg(not(static_dispatch_f64x2))]
+use crate::transpose_arbitrary_group::transpose_arbitrary_grouped_view;
+use crate::{FlipMode, FlopMode, TransposeError};
+#[cfg(not(static_dispatch_f64x2))]
+use alloc::boxed::Box;
+#[cfg(not(static_dispatch_f64x2))]
+use core::marker::PhantomData;
+
+#[cfg(not(static_dispatch_f64x2))]
+#[derive(Copy, Clone)]
+struct DefaultCbCrExecutor<F> {
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+    _phantom: PhantomData<F>,
+}
+
+#[cfg(not(static_dispatch_f64x2))]
+impl<F: Copy> TransposeExecutor<F> for DefaultCbCrExecutor<F> {
+    fn execute(
+        &self,
+        input: &[F],
+        input_stride: usize,
+        output: &mut [F],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        transpose_arbitrary_grouped_view::<F, 2>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            self.flip_mode,
+            self.flop_mode,
+        )
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+struct TransposeBlockNeon4x4F64x2<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f64>
+    for TransposeBlockNeon4x4F64x2<FLOP, FLIP>
+{
+    #[inline(always)]
+    fn transpose_block(&self, src: &[f64], src_stride: usize, dst: &mut [f64], dst_stride: usize) {
+        use crate::neon::neon_transpose_f64x2_4x4;
+        neon_transpose_f64x2_4x4::<FLOP, FLIP>(src, src_stride, dst, dst_stride);
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+struct TransposeBlockNeon2x2F64x2<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f64>
+    for TransposeBlockNeon2x2F64x2<FLOP, FLIP>
+{
+    #[inline(always)]
+    fn transpose_block(&self, src: &[f64], src_stride: usize, dst: &mut [f64], dst_stride: usize) {
+        use crate::neon::neon_transpose_f64x2_2x2;
+        neon_transpose_f64x2_2x2::<FLOP, FLIP>(src, src_stride, dst, dst_stride);
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse"
+))]
+struct TransposeBlockSse4x4F64x2<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse"
+))]
+impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f64>
+    for TransposeBlockSse4x4F64x2<FLOP, FLIP>
+{
+    #[inline(always)]
+    fn transpose_block(&self, src: &[f64], src_stride: usize, dst: &mut [f64], dst_stride: usize) {
+        use crate::sse::sse_transpose_f64x2_4x4;
+        unsafe { sse_transpose_f64x2_4x4::<FLOP, FLIP>(src, src_stride, dst, dst_stride) }
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse"
+))]
+struct TransposeBlockSse2x2F64x2<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse"
+))]
+impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f64>
+    for TransposeBlockSse2x2F64x2<FLOP, FLIP>
+{
+    #[inline(always)]
+    fn transpose_block(&self, src: &[f64], src_stride: usize, dst: &mut [f64], dst_stride: usize) {
+        use crate::sse::sse_transpose_f64x2_2x2;
+        unsafe { sse_transpose_f64x2_2x2::<FLOP, FLIP>(src, src_stride, dst, dst_stride) }
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
+struct TransposeBlockAvx4x4F64x2<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
+impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f64>
+    for TransposeBlockAvx4x4F64x2<FLOP, FLIP>
+{
+    #[inline(always)]
+    fn transpose_block(&self, src: &[f64], src_stride: usize, dst: &mut [f64], dst_stride: usize) {
+        use crate::avx::avx_transpose_f64x2_4x4;
+        unsafe { avx_transpose_f64x2_4x4::<FLOP, FLIP>(src, src_stride, dst, dst_stride) }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+#[derive(Copy, Clone, Default)]
+struct NeonDefaultExecutor<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse",
+    not(static_avx)
+))]
+#[derive(Copy, Clone, Default)]
+struct Sse2DefaultExecutor<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse",
+    not(static_avx)
+))]
+impl<const FLOP: bool, const FLIP: bool> Sse2DefaultExecutor<FLOP, FLIP> {
+    #[target_feature(enable = "sse2")]
+    unsafe fn execute_impl(
+        &self,
+        input: &[f64],
+        input_stride: usize,
+        output: &mut [f64],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        if !crate::utils::fits_strided(input.len(), input_stride, height, width * 2) {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output.len() != output_stride * width {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if input_stride < width * 2 {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output_stride < height * 2 {
+            return Err(TransposeError::MismatchDimensions);
+        }
+
+        let mut y = 0usize;
+
+        y = transpose_executor::<f64, 4, 2, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+            TransposeBlockSse4x4F64x2::<FLOP, FLIP> {},
+        );
+
+        y = transpose_executor::<f64, 2, 2, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+            TransposeBlockSse2x2F64x2::<FLOP, FLIP> {},
+        );
+
+        transpose_section::<f64, 2, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse",
+    not(static_avx)
+))]
+impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f64>
+    for Sse2DefaultExecutor<FLOP, FLIP>
+{
+    fn execute(
+        &self,
+        input: &[f64],
+        input_stride: usize,
+        output: &mut [f64],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        unsafe { self.execute_impl(input, input_stride, output, output_stride, width, height) }
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
+#[derive(Copy, Clone, Default)]
+struct AvxDefaultExecutor<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
+impl<const FLOP: bool, const FLIP: bool> AvxDefaultExecutor<FLOP, FLIP> {
+    #[target_feature(enable = "avx")]
+    unsafe fn execute_impl(
+        &self,
+        input: &[f64],
+        input_stride: usize,
+        output: &mut [f64],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        if !crate::utils::fits_strided(input.len(), input_stride, height, width * 2) {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output.len() != output_stride * width {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if input_stride < width * 2 {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output_stride < height * 2 {
+            return Err(TransposeError::MismatchDimensions);
+        }
+
+        let mut y = 0usize;
+
+        y = transpose_executor::<f64, 4, 2, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+            TransposeBlockAvx4x4F64x2::<FLOP, FLIP> {},
+        );
+
+        y = transpose_executor::<f64, 2, 2, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+            TransposeBlockSse2x2F64x2::<FLOP, FLIP> {},
+        );
+
+        transpose_section::<f64, 2, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
+impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f64> for AvxDefaultExecutor<FLOP, FLIP> {
+    fn execute(
+        &self,
+        input: &[f64],
+        input_stride: usize,
+        output: &mut [f64],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        unsafe { self.execute_impl(input, input_stride, output, output_stride, width, height) }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f64>
+    for NeonDefaultExecutor<FLOP, FLIP>
+{
+    fn execute(
+        &self,
+        input: &[f64],
+        input_stride: usize,
+        output: &mut [f64],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        if !crate::utils::fits_strided(input.len(), input_stride, height, width * 2) {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output.len() != output_stride * width {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if input_stride < width * 2 {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output_stride < height * 2 {
+            return Err(TransposeError::MismatchDimensions);
+        }
+
+        let mut y = 0usize;
+
+        y = transpose_executor::<f64, 4, 2, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+            TransposeBlockNeon4x4F64x2::<FLOP, FLIP> {},
+        );
+
+        y = transpose_executor::<f64, 2, 2, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+            TransposeBlockNeon2x2F64x2::<FLOP, FLIP> {},
+        );
+
+        transpose_section::<f64, 2, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(all(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "sse"
+    ),
+    not(static_dispatch_f64x2)
+))]
+fn make_sse2_default_executor(
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Option<Box<dyn TransposeExecutor<f64>>> {
+    if crate::utils::has_x86_feature!("sse2") {
+        return Some(match flip_mode {
+            FlipMode::NoFlip => match flop_mode {
+                FlopMode::NoFlop => Box::new(Sse2DefaultExecutor::<false, false>::default()),
+                FlopMode::Flop => Box::new(Sse2DefaultExecutor::<true, false>::default()),
+            },
+            FlipMode::Flip => match flop_mode {
+                FlopMode::NoFlop => Box::new(Sse2DefaultExecutor::<false, true>::default()),
+                FlopMode::Flop => Box::new(Sse2DefaultExecutor::<true, true>::default()),
+            },
+        });
+    }
+    None
+}
+
+#[cfg(all(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "avx"
+    ),
+    not(static_dispatch_f64x2)
+))]
+fn make_avx_default_executor(
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Option<Box<dyn TransposeExecutor<f64>>> {
+    if crate::utils::has_x86_feature!("avx") {
+        return Some(match flip_mode {
+            FlipMode::NoFlip => match flop_mode {
+                FlopMode::NoFlop => Box::new(AvxDefaultExecutor::<false, false>::default()),
+                FlopMode::Flop => Box::new(AvxDefaultExecutor::<true, false>::default()),
+            },
+            FlipMode::Flip => match flop_mode {
+                FlopMode::NoFlop => Box::new(AvxDefaultExecutor::<false, true>::default()),
+                FlopMode::Flop => Box::new(AvxDefaultExecutor::<true, true>::default()),
+            },
+        });
+    }
+    None
+}
+
+#[cfg(not(static_dispatch_f64x2))]
+pub(crate) fn prepare_f64_cbcr_executor(
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Box<dyn TransposeExecutor<f64>> {
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "avx"
+    ))]
+    {
+        if crate::utils::has_x86_feature!("avx") {
+            if let Some(executor) = make_avx_default_executor(flip_mode, flop_mode) {
+                return executor;
+            }
+        }
+    }
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "sse"
+    ))]
+    {
+        if crate::utils::has_x86_feature!("sse2") {
+            if let Some(executor) = make_sse2_default_executor(flip_mode, flop_mode) {
+                return executor;
+            }
+        }
+    }
+    Box::new(DefaultCbCrExecutor {
+        flip_mode,
+        flop_mode,
+        _phantom: PhantomData,
+    })
+}
+
+#[cfg(static_dispatch_f64x2)]
+fn execute_static<const FLOP: bool, const FLIP: bool>(
+    input: &[f64],
+    input_stride: usize,
+    output: &mut [f64],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+    {
+        NeonDefaultExecutor::<FLOP, FLIP>::default().execute(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+        )
+    }
+    #[cfg(static_avx)]
+    {
+        AvxDefaultExecutor::<FLOP, FLIP>::default().execute(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+        )
+    }
+    #[cfg(all(static_sse2, not(feature = "avx")))]
+    {
+        Sse2DefaultExecutor::<FLOP, FLIP>::default().execute(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+        )
+    }
+}
+
+/// Executes f64_cbcr transposition, kernel is selected at compile time when
+/// target features allow it, otherwise at runtime.
+pub(crate) fn execute_f64_cbcr(
+    input: &[f64],
+    input_stride: usize,
+    output: &mut [f64],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    #[cfg(static_dispatch_f64x2)]
+    {
+        crate::utils::dispatch_flip_flop!(
+            flip_mode,
+            flop_mode,
+            execute_static,
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height
+        )
+    }
+    #[cfg(not(static_dispatch_f64x2))]
+    {
+        let executor = prepare_f64_cbcr_executor(flip_mode, flop_mode);
+        executor.execute(input, input_stride, output, output_stride, width, height)
+    }
+}
@@ -0,0 +1,649 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[allow(unused_imports)]
+use crate::float32_invoker::{DefaultExecutor, TransposeExecutor};
+#[allow(unused_imports)]
+use crate::rgba8::*;
+use crate::{FlipMode, FlopMode, TransposeError};
+#[cfg(not(static_dispatch_f64))]
+use alloc::boxed::Box;
+#[cfg(not(static_dispatch_f64))]
+use core::marker::PhantomData;
+
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+struct TransposeBlockNeon2x2F64<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f64>
+    for TransposeBlockNeon2x2F64<FLOP, FLIP>
+{
+    #[inline(always)]
+    fn transpose_block(&self, src: &[f64], src_stride: usize, dst: &mut [f64], dst_stride: usize) {
+        use crate::neon::neon_transpose_2x2_f64;
+        neon_transpose_2x2_f64::<FLOP, FLIP>(src, src_stride, dst, dst_stride);
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse"
+))]
+struct TransposeBlockSse2x2F64<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse"
+))]
+impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f64>
+    for TransposeBlockSse2x2F64<FLOP, FLIP>
+{
+    #[inline(always)]
+    fn transpose_block(&self, src: &[f64], src_stride: usize, dst: &mut [f64], dst_stride: usize) {
+        use crate::sse::sse_transpose_2x2_f64;
+        unsafe { sse_transpose_2x2_f64::<FLOP, FLIP>(src, src_stride, dst, dst_stride) }
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
+struct TransposeBlockAvx4x4F64<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
+impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f64>
+    for TransposeBlockAvx4x4F64<FLOP, FLIP>
+{
+    #[inline(always)]
+    fn transpose_block(&self, src: &[f64], src_stride: usize, dst: &mut [f64], dst_stride: usize) {
+        use crate::avx::avx_transpose_4x4_f64;
+        unsafe { avx_transpose_4x4_f64::<FLOP, FLIP>(src, src_stride, dst, dst_stride) }
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "nightly_avx512"))]
+struct TransposeBlockAvx512_8x8F64<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(target_arch = "x86_64", feature = "nightly_avx512"))]
+impl<const FLOP: bool, const FLIP: bool> TransposeBlock<f64>
+    for TransposeBlockAvx512_8x8F64<FLOP, FLIP>
+{
+    #[inline(always)]
+    fn transpose_block(&self, src: &[f64], src_stride: usize, dst: &mut [f64], dst_stride: usize) {
+        use crate::avx512::avx512_transpose_8x8_f64;
+        avx512_transpose_8x8_f64::<FLOP, FLIP>(src, src_stride, dst, dst_stride);
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+#[derive(Copy, Clone, Default)]
+struct NeonDefaultExecutor<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f64>
+    for NeonDefaultExecutor<FLOP, FLIP>
+{
+    fn execute(
+        &self,
+        input: &[f64],
+        input_stride: usize,
+        output: &mut [f64],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        if !crate::utils::fits_strided(input.len(), input_stride, height, width) {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output.len() != output_stride * width {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if input_stride < width {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output_stride < height {
+            return Err(TransposeError::MismatchDimensions);
+        }
+
+        let mut y = 0usize;
+
+        y = transpose_executor::<f64, 2, 1, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+            TransposeBlockNeon2x2F64::<FLOP, FLIP> {},
+        );
+
+        transpose_section::<f64, 1, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse",
+    not(all(static_dispatch_f64, feature = "avx"))
+))]
+#[derive(Copy, Clone, Default)]
+struct Sse2DefaultExecutor<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "sse"
+    ),
+    not(static_dispatch_f64)
+))]
+fn make_sse2_default_executor(
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Option<Box<dyn TransposeExecutor<f64>>> {
+    if crate::utils::has_x86_feature!("sse2") {
+        return Some(match flip_mode {
+            FlipMode::NoFlip => match flop_mode {
+                FlopMode::NoFlop => Box::new(Sse2DefaultExecutor::<false, false>::default()),
+                FlopMode::Flop => Box::new(Sse2DefaultExecutor::<true, false>::default()),
+            },
+            FlipMode::Flip => match flop_mode {
+                FlopMode::NoFlop => Box::new(Sse2DefaultExecutor::<false, true>::default()),
+                FlopMode::Flop => Box::new(Sse2DefaultExecutor::<true, true>::default()),
+            },
+        });
+    }
+    None
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse",
+    not(all(static_dispatch_f64, feature = "avx"))
+))]
+impl<const FLOP: bool, const FLIP: bool> Sse2DefaultExecutor<FLOP, FLIP> {
+    #[target_feature(enable = "sse2")]
+    unsafe fn execute_impl(
+        &self,
+        input: &[f64],
+        input_stride: usize,
+        output: &mut [f64],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        if !crate::utils::fits_strided(input.len(), input_stride, height, width) {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output.len() != output_stride * width {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if input_stride < width {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output_stride < height {
+            return Err(TransposeError::MismatchDimensions);
+        }
+
+        let mut y = 0usize;
+
+        y = transpose_executor::<f64, 2, 1, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+            TransposeBlockSse2x2F64::<FLOP, FLIP> {},
+        );
+
+        transpose_section::<f64, 1, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse",
+    not(all(static_dispatch_f64, feature = "avx"))
+))]
+impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f64>
+    for Sse2DefaultExecutor<FLOP, FLIP>
+{
+    fn execute(
+        &self,
+        input: &[f64],
+        input_stride: usize,
+        output: &mut [f64],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        unsafe { self.execute_impl(input, input_stride, output, output_stride, width, height) }
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx",
+    not(static_avx512f)
+))]
+#[derive(Copy, Clone, Default)]
+struct AvxDefaultExecutor<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "avx"
+    ),
+    not(static_dispatch_f64)
+))]
+fn make_avx_default_executor(
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Option<Box<dyn TransposeExecutor<f64>>> {
+    if crate::utils::has_x86_feature!("avx") {
+        return Some(match flip_mode {
+            FlipMode::NoFlip => match flop_mode {
+                FlopMode::NoFlop => Box::new(AvxDefaultExecutor::<false, false>::default()),
+                FlopMode::Flop => Box::new(AvxDefaultExecutor::<true, false>::default()),
+            },
+            FlipMode::Flip => match flop_mode {
+                FlopMode::NoFlop => Box::new(AvxDefaultExecutor::<false, true>::default()),
+                FlopMode::Flop => Box::new(AvxDefaultExecutor::<true, true>::default()),
+            },
+        });
+    }
+    None
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx",
+    not(static_avx512f)
+))]
+impl<const FLOP: bool, const FLIP: bool> AvxDefaultExecutor<FLOP, FLIP> {
+    #[target_feature(enable = "avx")]
+    unsafe fn execute_impl(
+        &self,
+        input: &[f64],
+        input_stride: usize,
+        output: &mut [f64],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        if !crate::utils::fits_strided(input.len(), input_stride, height, width) {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output.len() != output_stride * width {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if input_stride < width {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output_stride < height {
+            return Err(TransposeError::MismatchDimensions);
+        }
+
+        let mut y = 0usize;
+
+        y = transpose_executor::<f64, 4, 1, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+            TransposeBlockAvx4x4F64::<FLOP, FLIP> {},
+        );
+
+        y = transpose_executor::<f64, 2, 1, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+            TransposeBlockSse2x2F64::<FLOP, FLIP> {},
+        );
+
+        transpose_section::<f64, 1, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx",
+    not(static_avx512f)
+))]
+impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f64> for AvxDefaultExecutor<FLOP, FLIP> {
+    fn execute(
+        &self,
+        input: &[f64],
+        input_stride: usize,
+        output: &mut [f64],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        unsafe { self.execute_impl(input, input_stride, output, output_stride, width, height) }
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "nightly_avx512"))]
+#[derive(Copy, Clone, Default)]
+struct Avx512DefaultExecutor<const FLOP: bool, const FLIP: bool> {}
+
+#[cfg(all(
+    all(target_arch = "x86_64", feature = "nightly_avx512"),
+    not(static_dispatch_f64)
+))]
+fn make_avx512_default_executor(
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Option<Box<dyn TransposeExecutor<f64>>> {
+    if crate::utils::has_x86_feature!("avx512f") {
+        return Some(match flip_mode {
+            FlipMode::NoFlip => match flop_mode {
+                FlopMode::NoFlop => Box::new(Avx512DefaultExecutor::<false, false>::default()),
+                FlopMode::Flop => Box::new(Avx512DefaultExecutor::<true, false>::default()),
+            },
+            FlipMode::Flip => match flop_mode {
+                FlopMode::NoFlop => Box::new(Avx512DefaultExecutor::<false, true>::default()),
+                FlopMode::Flop => Box::new(Avx512DefaultExecutor::<true, true>::default()),
+            },
+        });
+    }
+    None
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "nightly_avx512"))]
+impl<const FLOP: bool, const FLIP: bool> Avx512DefaultExecutor<FLOP, FLIP> {
+    #[target_feature(enable = "avx512f")]
+    unsafe fn execute_impl(
+        &self,
+        input: &[f64],
+        input_stride: usize,
+        output: &mut [f64],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        if !crate::utils::fits_strided(input.len(), input_stride, height, width) {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output.len() != output_stride * width {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if input_stride < width {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if output_stride < height {
+            return Err(TransposeError::MismatchDimensions);
+        }
+
+        let mut y = 0usize;
+
+        y = transpose_executor::<f64, 8, 1, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+            TransposeBlockAvx512_8x8F64::<FLOP, FLIP> {},
+        );
+
+        y = transpose_executor::<f64, 4, 1, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+            TransposeBlockAvx4x4F64::<FLOP, FLIP> {},
+        );
+
+        y = transpose_executor::<f64, 2, 1, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+            TransposeBlockSse2x2F64::<FLOP, FLIP> {},
+        );
+
+        transpose_section::<f64, 1, FLOP, FLIP>(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            y,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(all(target_arch = "x86_64", feature = "nightly_avx512"))]
+impl<const FLOP: bool, const FLIP: bool> TransposeExecutor<f64>
+    for Avx512DefaultExecutor<FLOP, FLIP>
+{
+    fn execute(
+        &self,
+        input: &[f64],
+        input_stride: usize,
+        output: &mut [f64],
+        output_stride: usize,
+        width: usize,
+        height: usize,
+    ) -> Result<(), TransposeError> {
+        unsafe { self.execute_impl(input, input_stride, output, output_stride, width, height) }
+    }
+}
+
+#[cfg(not(static_dispatch_f64))]
+fn prepare_f64_plane_executor(
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Box<dyn TransposeExecutor<f64>> {
+    #[cfg(all(target_arch = "x86_64", feature = "nightly_avx512"))]
+    {
+        if let Some(executor) = make_avx512_default_executor(flip_mode, flop_mode) {
+            return executor;
+        }
+    }
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "avx"
+    ))]
+    {
+        if let Some(executor) = make_avx_default_executor(flip_mode, flop_mode) {
+            return executor;
+        }
+    }
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "sse"
+    ))]
+    {
+        if let Some(executor) = make_sse2_default_executor(flip_mode, flop_mode) {
+            return executor;
+        }
+    }
+    Box::new(DefaultExecutor {
+        flip_mode,
+        flop_mode,
+        _phantom: PhantomData,
+    })
+}
+
+#[cfg(static_dispatch_f64)]
+fn execute_static<const FLOP: bool, const FLIP: bool>(
+    input: &[f64],
+    input_stride: usize,
+    output: &mut [f64],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+    {
+        NeonDefaultExecutor::<FLOP, FLIP>::default().execute(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+        )
+    }
+    #[cfg(static_avx512f)]
+    {
+        Avx512DefaultExecutor::<FLOP, FLIP>::default().execute(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+        )
+    }
+    #[cfg(all(static_avx, not(feature = "nightly_avx512")))]
+    {
+        AvxDefaultExecutor::<FLOP, FLIP>::default().execute(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+        )
+    }
+    #[cfg(all(static_sse2, not(feature = "avx")))]
+    {
+        Sse2DefaultExecutor::<FLOP, FLIP>::default().execute(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+        )
+    }
+}
+
+/// Executes f64_plane transposition, kernel is selected at compile time when
+/// target features allow it, otherwise at runtime.
+pub(crate) fn execute_f64_plane(
+    input: &[f64],
+    input_stride: usize,
+    output: &mut [f64],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    #[cfg(static_dispatch_f64)]
+    {
+        crate::utils::dispatch_flip_flop!(
+            flip_mode,
+            flop_mode,
+            execute_static,
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height
+        )
+    }
+    #[cfg(not(static_dispatch_f64))]
+    {
+        let executor = prepare_f64_plane_executor(flip_mode, flop_mode);
+        executor.execute(input, input_stride, output, output_stride, width, height)
+    }
+}
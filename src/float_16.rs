@@ -0,0 +1,589 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::{
+    flip_plane16, flip_plane16_with_alpha, flip_rgb16, flip_rgba16, flop_plane16,
+    flop_plane16_with_alpha, flop_rgb16, flop_rgba16, rotate180_plane16,
+    rotate180_plane16_with_alpha, rotate180_rgb16, rotate180_rgba16, transpose_plane16,
+    transpose_plane16_with_alpha, transpose_rgb16, transpose_rgba16, FlipMode, FlopMode,
+    TransposeError,
+};
+use half::f16;
+
+/// Performs plane image transposition
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_plane_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_plane16(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Performs plane with alpha image transposition
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_plane_with_alpha_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_plane16_with_alpha(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Performs RGB image transposition
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_rgb_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_rgb16(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Performs RGBA image transposition
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_rgba_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_rgba16(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Performs plane image flipping
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flip_plane_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    flip_plane16(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+    )
+}
+
+/// Performs plane with alpha image flipping
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flip_plane_with_alpha_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    flip_plane16_with_alpha(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+    )
+}
+
+/// Performs RGB image flipping
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flip_rgb_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    flip_rgb16(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+    )
+}
+
+/// Performs RGBA image flipping
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flip_rgba_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    flip_rgba16(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+    )
+}
+
+/// Performs plane image flopping
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flop_plane_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    flop_plane16(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+    )
+}
+
+/// Performs plane with alpha image flopping
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flop_plane_with_alpha_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    flop_plane16_with_alpha(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+    )
+}
+
+/// Performs RGB image flopping
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flop_rgb_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    flop_rgb16(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+    )
+}
+
+/// Performs RGBA image flopping
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flop_rgba_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    flop_rgba16(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+    )
+}
+
+/// Performs plane image rotating by 180
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate180_plane_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    rotate180_plane16(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+    )
+}
+
+/// Performs plane with alpha image rotating by 180
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate180_plane_with_alpha_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    rotate180_plane16_with_alpha(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+    )
+}
+
+/// Performs RGB image rotating by 180
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate180_rgb_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    rotate180_rgb16(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+    )
+}
+
+/// Performs RGBA image rotating by 180
+///
+/// Samples are processed bit for bit as 16-bit values.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate180_rgba_f16(
+    input: &[f16],
+    input_stride: usize,
+    output: &mut [f16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    rotate180_rgba16(
+        bytemuck::cast_slice(input),
+        input_stride,
+        bytemuck::cast_slice_mut(output),
+        output_stride,
+        width,
+        height,
+    )
+}
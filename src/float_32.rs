@@ -27,9 +27,13 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 #![forbid(unsafe_code)]
-use crate::float32_cbcr_invoker::prepare_f32_cbcr_executor;
-use crate::float32_invoker::prepare_f32_plane_executor;
+use crate::float32_cbcr_invoker::execute_f32_cbcr;
+use crate::float32_invoker::execute_f32_plane;
+#[cfg(feature = "rayon")]
+use crate::parallel::transpose_par;
 use crate::transpose_arbitrary_group::transpose_arbitrary_grouped;
+#[cfg(feature = "rayon")]
+use crate::transpose_arbitrary_group::transpose_arbitrary_grouped_view;
 use crate::{FlipMode, FlopMode, TransposeError};
 
 /// Performs plane image transposition
@@ -57,8 +61,61 @@ pub fn transpose_plane_f32(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Result<(), TransposeError> {
-    let executor = prepare_f32_plane_executor(flip_mode, flop_mode);
-    executor.execute(input, input_stride, output, output_stride, width, height)
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    execute_f32_plane(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Performs plane image transposition using multiple threads
+///
+/// Work is split into bands of output rows processed by rayon thread pool,
+/// small images are transposed on the calling thread.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+#[cfg(feature = "rayon")]
+pub fn transpose_plane_f32_par(
+    input: &[f32],
+    input_stride: usize,
+    output: &mut [f32],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_par::<f32, 1>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        execute_f32_plane,
+    )
 }
 
 /// Performs plane with alpha image transposition
@@ -86,8 +143,61 @@ pub fn transpose_plane_f32_with_alpha(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Result<(), TransposeError> {
-    let executor = prepare_f32_cbcr_executor(flip_mode, flop_mode);
-    executor.execute(input, input_stride, output, output_stride, width, height)
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    execute_f32_cbcr(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Performs plane with alpha image transposition using multiple threads
+///
+/// Work is split into bands of output rows processed by rayon thread pool,
+/// small images are transposed on the calling thread.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+#[cfg(feature = "rayon")]
+pub fn transpose_plane_f32_with_alpha_par(
+    input: &[f32],
+    input_stride: usize,
+    output: &mut [f32],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_par::<f32, 2>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        execute_f32_cbcr,
+    )
 }
 
 /// Performs RGB image transposition
@@ -127,6 +237,48 @@ pub fn transpose_rgb_f32(
     )
 }
 
+/// Performs RGB image transposition using multiple threads
+///
+/// Work is split into bands of output rows processed by rayon thread pool,
+/// small images are transposed on the calling thread.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+#[cfg(feature = "rayon")]
+pub fn transpose_rgb_f32_par(
+    input: &[f32],
+    input_stride: usize,
+    output: &mut [f32],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_par::<f32, 3>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_arbitrary_grouped_view::<f32, 3>,
+    )
+}
+
 /// Performs RGBA image transposition
 ///
 /// # Arguments
@@ -163,3 +315,45 @@ pub fn transpose_rgba_f32(
         flop_mode,
     )
 }
+
+/// Performs RGBA image transposition using multiple threads
+///
+/// Work is split into bands of output rows processed by rayon thread pool,
+/// small images are transposed on the calling thread.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+#[cfg(feature = "rayon")]
+pub fn transpose_rgba_f32_par(
+    input: &[f32],
+    input_stride: usize,
+    output: &mut [f32],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_par::<f32, 4>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_arbitrary_grouped_view::<f32, 4>,
+    )
+}
@@ -27,6 +27,8 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 use crate::TransposeError;
+#[allow(unused_imports)]
+use alloc::boxed::Box;
 use bytemuck::{AnyBitPattern, NoUninit, Pod};
 
 trait Flopper<V: Copy> {
@@ -58,14 +60,16 @@ macro_rules! flop_grouped_copy {
     };
 }
 
+#[cfg(not(static_flop))]
 #[derive(Debug, Copy, Clone, Default)]
 struct CommonGroupedFlopper<V: Copy + Pod + NoUninit + AnyBitPattern, const N: usize>
 where
     [V; N]: Pod,
 {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
+#[cfg(not(static_flop))]
 impl<V: Copy + Pod + NoUninit + AnyBitPattern, const N: usize> Flopper<V>
     for CommonGroupedFlopper<V, N>
 where
@@ -85,7 +89,7 @@ where
 
 #[derive(Debug, Copy, Clone, Default)]
 struct CommonFlopper<V: Copy> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 impl<V: Copy> Flopper<V> for CommonFlopper<V> {
@@ -113,13 +117,17 @@ impl<V: Copy> Flopper<V> for CommonFlopper<V> {
 
 #[derive(Debug, Copy, Clone, Default)]
 struct FlopperGroupedFactory<V: Copy + Pod + NoUninit + AnyBitPattern, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(x86_avx512f)
+))]
 #[derive(Debug, Copy, Clone, Default)]
 struct FlopperAvx2GroupedFactory<V: Copy + Pod + NoUninit + AnyBitPattern, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 #[cfg(all(
@@ -128,10 +136,14 @@ struct FlopperAvx2GroupedFactory<V: Copy + Pod + NoUninit + AnyBitPattern, const
 ))]
 #[derive(Debug, Copy, Clone, Default)]
 struct FlopperAvx512GroupedFactory<V: Copy + Pod + NoUninit + AnyBitPattern, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(x86_avx512f)
+))]
 impl<V: Copy + 'static + Copy + Pod + NoUninit + AnyBitPattern, const N: usize>
     FlopperAvx2GroupedFactory<V, N>
 where
@@ -196,7 +208,11 @@ where
     }
 }
 
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(x86_avx512f)
+))]
 impl<V: Copy + 'static + Copy + Pod + NoUninit + AnyBitPattern, const N: usize> Flopper<V>
     for FlopperAvx2GroupedFactory<V, N>
 where
@@ -221,29 +237,43 @@ where
     V: Default,
     [V; N]: Pod,
 {
-    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+    #[cfg(x86_avx512f)]
+    fn make_flipper(&self) -> FlopperAvx512GroupedFactory<V, N> {
+        FlopperAvx512GroupedFactory::<V, N>::default()
+    }
+
+    #[cfg(all(x86_avx2, not(feature = "nightly_avx512")))]
+    fn make_flipper(&self) -> FlopperAvx2GroupedFactory<V, N> {
+        FlopperAvx2GroupedFactory::<V, N>::default()
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        not(static_flop)
+    ))]
     fn make_flipper(&self) -> Box<dyn Flopper<V>> {
         #[cfg(feature = "nightly_avx512")]
-        if std::arch::is_x86_feature_detected!("avx512f") {
+        if crate::utils::has_x86_feature!("avx512f") {
             return Box::new(FlopperAvx512GroupedFactory::<V, N>::default());
         }
-        if std::arch::is_x86_feature_detected!("avx2") {
+        if crate::utils::has_x86_feature!("avx2") {
             return Box::new(FlopperAvx2GroupedFactory::<V, N>::default());
         }
         Box::new(CommonGroupedFlopper::<V, N>::default())
     }
 
     #[cfg(all(target_arch = "aarch64", feature = "unsafe"))]
-    fn make_flipper(&self) -> Box<dyn Flopper<V>> {
-        Box::new(CommonGroupedFlopper::<V, N>::default())
+    fn make_flipper(&self) -> CommonGroupedFlopper<V, N> {
+        CommonGroupedFlopper::<V, N>::default()
     }
 
     #[cfg(not(any(
         all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"),
         all(target_arch = "aarch64", feature = "unsafe")
     )))]
-    fn make_flipper(&self) -> Box<dyn Flopper<V>> {
-        Box::new(CommonGroupedFlopper::<V, N>::default())
+    fn make_flipper(&self) -> CommonGroupedFlopper<V, N> {
+        CommonGroupedFlopper::<V, N>::default()
     }
 }
 
@@ -287,6 +317,43 @@ pub fn flop_arbitrary<V: Copy + Default>(
     Ok(())
 }
 
+/// Performs flopping of `Pod` types
+///
+/// Types of 1, 2, 4, 8 or 16 bytes are processed as unsigned integers or `u64` pairs of the same size,
+/// others are handled as in [flop_arbitrary].
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Array width
+/// * `height`: Array height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flop_pod<V: Pod + Default>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    crate::utils::dispatch_pod_size!(
+        V,
+        flop_arbitrary,
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height
+    )
+    .unwrap_or_else(|| flop_arbitrary(input, input_stride, output, output_stride, width, height))
+}
+
 /// Performs arbitrary flopping for groups
 ///
 /// # Arguments
@@ -617,3 +684,27 @@ pub fn flop_rgba_f32(
 ) -> Result<(), TransposeError> {
     flop_arbitrary_grouped::<f32, 4>(input, input_stride, output, output_stride, width, height)
 }
+
+/// Performs plane image flopping
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Array width
+/// * `height`: Array height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn flop_plane_f64(
+    input: &[f64],
+    input_stride: usize,
+    output: &mut [f64],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    flop_arbitrary(input, input_stride, output, output_stride, width, height)
+}
@@ -0,0 +1,1446 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::float32_invoker::execute_f32_plane;
+use crate::orientation::check_plane;
+use crate::plane16::transpose_plane16_chunked;
+use crate::plane8::transpose_plane8_chunked;
+use crate::tile::TransposeFn;
+use crate::{FlipMode, FlopMode, TransposeError};
+use alloc::vec;
+use bytemuck::Pod;
+
+/// Converts one row between interleaved and planar layouts
+trait RowInterleaver<V, const CN: usize> {
+    fn deinterleave(&self, src: &[V], dst: &mut [&mut [V]; CN], width: usize);
+
+    fn interleave(&self, src: &[&[V]; CN], dst: &mut [V], width: usize);
+}
+
+#[cfg(not(static_neon))]
+#[inline(always)]
+fn deinterleave_row<V: Copy, const CN: usize>(src: &[V], dst: &mut [&mut [V]; CN], width: usize) {
+    let src = &src[..width * CN];
+    let mut dst = dst.each_mut().map(|plane| &mut plane[..width]);
+    for (x, px) in src.chunks_exact(CN).enumerate() {
+        for (plane, &v) in dst.iter_mut().zip(px) {
+            plane[x] = v;
+        }
+    }
+}
+
+#[cfg(not(static_neon))]
+#[inline(always)]
+fn interleave_row<V: Copy, const CN: usize>(src: &[&[V]; CN], dst: &mut [V], width: usize) {
+    let src = src.map(|plane| &plane[..width]);
+    let dst = &mut dst[..width * CN];
+    for (x, px) in dst.chunks_exact_mut(CN).enumerate() {
+        for (v, plane) in px.iter_mut().zip(src.iter()) {
+            *v = plane[x];
+        }
+    }
+}
+
+#[cfg(not(static_neon))]
+#[derive(Debug, Copy, Clone, Default)]
+struct CommonInterleaver {}
+
+#[cfg(not(static_neon))]
+impl<V: Copy, const CN: usize> RowInterleaver<V, CN> for CommonInterleaver {
+    #[inline(always)]
+    fn deinterleave(&self, src: &[V], dst: &mut [&mut [V]; CN], width: usize) {
+        deinterleave_row(src, dst, width);
+    }
+
+    #[inline(always)]
+    fn interleave(&self, src: &[&[V]; CN], dst: &mut [V], width: usize) {
+        interleave_row(src, dst, width);
+    }
+}
+
+/// Sample types supported by interleaving
+trait InterleaveSample: Copy + Default + 'static {
+    #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+    fn neon_deinterleave<const CN: usize>(src: &[Self], dst: &mut [&mut [Self]; CN], width: usize);
+
+    #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+    fn neon_interleave<const CN: usize>(src: &[&[Self]; CN], dst: &mut [Self], width: usize);
+}
+
+macro_rules! impl_interleave_sample {
+    ($v:ty, $deinterleave:ident, $interleave:ident) => {
+        impl InterleaveSample for $v {
+            #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+            #[inline(always)]
+            fn neon_deinterleave<const CN: usize>(
+                src: &[Self],
+                dst: &mut [&mut [Self]; CN],
+                width: usize,
+            ) {
+                crate::neon::$deinterleave::<CN>(src, dst, width);
+            }
+
+            #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+            #[inline(always)]
+            fn neon_interleave<const CN: usize>(
+                src: &[&[Self]; CN],
+                dst: &mut [Self],
+                width: usize,
+            ) {
+                crate::neon::$interleave::<CN>(src, dst, width);
+            }
+        }
+    };
+}
+
+impl_interleave_sample!(u8, neon_deinterleave_row_u8, neon_interleave_row_u8);
+impl_interleave_sample!(u16, neon_deinterleave_row_u16, neon_interleave_row_u16);
+impl_interleave_sample!(u32, neon_deinterleave_row_u32, neon_interleave_row_u32);
+impl_interleave_sample!(f32, neon_deinterleave_row_f32, neon_interleave_row_f32);
+
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+#[derive(Debug, Copy, Clone, Default)]
+struct NeonInterleaver {}
+
+#[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
+impl<V: InterleaveSample, const CN: usize> RowInterleaver<V, CN> for NeonInterleaver {
+    #[inline(always)]
+    fn deinterleave(&self, src: &[V], dst: &mut [&mut [V]; CN], width: usize) {
+        V::neon_deinterleave::<CN>(src, dst, width);
+    }
+
+    #[inline(always)]
+    fn interleave(&self, src: &[&[V]; CN], dst: &mut [V], width: usize) {
+        V::neon_interleave::<CN>(src, dst, width);
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
+#[derive(Debug, Copy, Clone, Default)]
+struct Avx2Interleaver {}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
+impl<V: InterleaveSample, const CN: usize> RowInterleaver<V, CN> for Avx2Interleaver {
+    #[inline(always)]
+    fn deinterleave(&self, src: &[V], dst: &mut [&mut [V]; CN], width: usize) {
+        let x = unsafe { crate::avx::avx2_deinterleave_row(src, dst, width) };
+        let mut dst = dst.each_mut().map(|plane| &mut plane[x..]);
+        deinterleave_row(&src[x * CN..], &mut dst, width - x);
+    }
+
+    #[inline(always)]
+    fn interleave(&self, src: &[&[V]; CN], dst: &mut [V], width: usize) {
+        let x = unsafe { crate::avx::avx2_interleave_row(src, dst, width) };
+        let src = src.map(|plane| &plane[x..]);
+        interleave_row(&src, &mut dst[x * CN..], width - x);
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse"
+))]
+#[derive(Debug, Copy, Clone, Default)]
+struct Ssse3Interleaver {}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "sse"
+))]
+impl<V: InterleaveSample, const CN: usize> RowInterleaver<V, CN> for Ssse3Interleaver {
+    #[inline(always)]
+    fn deinterleave(&self, src: &[V], dst: &mut [&mut [V]; CN], width: usize) {
+        let x = unsafe { crate::sse::ssse3_deinterleave_row(src, dst, width) };
+        let mut dst = dst.each_mut().map(|plane| &mut plane[x..]);
+        deinterleave_row(&src[x * CN..], &mut dst, width - x);
+    }
+
+    #[inline(always)]
+    fn interleave(&self, src: &[&[V]; CN], dst: &mut [V], width: usize) {
+        let x = unsafe { crate::sse::ssse3_interleave_row(src, dst, width) };
+        let src = src.map(|plane| &plane[x..]);
+        interleave_row(&src, &mut dst[x * CN..], width - x);
+    }
+}
+
+/// Calls `$executor` with the best rows converter available on the CPU,
+/// so the converter is called directly and may be inlined.
+macro_rules! dispatch_interleaver {
+    ($executor:ident, $($arg:expr),+ $(,)?) => {{
+        #[cfg(static_neon)]
+        {
+            $executor(&NeonInterleaver {}, $($arg),+)
+        }
+        #[cfg(not(static_neon))]
+        {
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "unsafe",
+                feature = "avx"
+            ))]
+            if crate::utils::has_x86_feature!("avx2") {
+                return $executor(&Avx2Interleaver {}, $($arg),+);
+            }
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "unsafe",
+                feature = "sse"
+            ))]
+            if crate::utils::has_x86_feature!("ssse3") {
+                return $executor(&Ssse3Interleaver {}, $($arg),+);
+            }
+            $executor(&CommonInterleaver {}, $($arg),+)
+        }
+    }};
+}
+
+/// Approximate amount of bytes of deinterleaved columns processed at once
+const BAND_BYTES: usize = 1024 * 1024;
+
+/// Least amount of columns deinterleaved at once, tall images are split into bands of rows instead
+const MIN_STRIP: usize = 64;
+
+/// Borrows row `y` of every plane
+fn plane_rows<'a, V, const CN: usize>(
+    planes: &'a mut [&mut [V]; CN],
+    strides: &[usize; CN],
+    y: usize,
+) -> [&'a mut [V]; CN] {
+    let mut rows = planes
+        .iter_mut()
+        .zip(strides.iter())
+        .map(|(plane, &stride)| &mut plane[y * stride..]);
+    core::array::from_fn(|_| rows.next().unwrap())
+}
+
+fn deinterleave_impl<V: InterleaveSample, const CN: usize, I: RowInterleaver<V, CN>>(
+    interleaver: &I,
+    input: &[V],
+    input_stride: usize,
+    mut output: [&mut [V]; CN],
+    output_strides: [usize; CN],
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    check_plane(input.len(), input_stride, width * CN, height)?;
+    for (plane, &stride) in output.iter().zip(output_strides.iter()) {
+        check_plane(plane.len(), stride, width, height)?;
+    }
+    for y in 0..height {
+        let mut dst = plane_rows(&mut output, &output_strides, y);
+        interleaver.deinterleave(&input[y * input_stride..], &mut dst, width);
+    }
+    Ok(())
+}
+
+fn interleave_impl<V: InterleaveSample, const CN: usize, I: RowInterleaver<V, CN>>(
+    interleaver: &I,
+    input: [&[V]; CN],
+    input_strides: [usize; CN],
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    for (plane, &stride) in input.iter().zip(input_strides.iter()) {
+        check_plane(plane.len(), stride, width, height)?;
+    }
+    check_plane(output.len(), output_stride, width * CN, height)?;
+    for y in 0..height {
+        let src = core::array::from_fn(|c| &input[c][y * input_strides[c]..]);
+        interleaver.interleave(&src, &mut output[y * output_stride..], width);
+    }
+    Ok(())
+}
+
+fn transpose_deinterleave_impl<V: InterleaveSample, const CN: usize, I: RowInterleaver<V, CN>>(
+    interleaver: &I,
+    input: &[V],
+    input_stride: usize,
+    mut output: [&mut [V]; CN],
+    output_strides: [usize; CN],
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+    executor: TransposeFn<V>,
+) -> Result<(), TransposeError> {
+    check_plane(input.len(), input_stride, width * CN, height)?;
+    for (plane, &stride) in output.iter().zip(output_strides.iter()) {
+        check_plane(plane.len(), stride, height, width)?;
+    }
+    if width == 0 || height == 0 {
+        return Ok(());
+    }
+
+    // Tile of input columns and rows is deinterleaved into scratch planes,
+    // then each plane is transposed into output rows with block kernels.
+    // Tiles spanning the whole height are written in place, shorter ones are
+    // transposed into a scratch tile and copied into their output columns.
+    let size = size_of::<V>();
+    let mut strip = (BAND_BYTES / (height * CN * size)).clamp(MIN_STRIP.min(width), width);
+    if strip >= 64 {
+        strip -= strip % 64;
+    }
+    let band = (BAND_BYTES / (strip * CN * size)).clamp(1, height);
+    let mut scratch = vec![V::default(); strip * band * CN];
+    let mut transposed = if band < height {
+        vec![V::default(); strip * band]
+    } else {
+        vec![]
+    };
+
+    for x in (0..width).step_by(strip) {
+        let columns = strip.min(width - x);
+        // Output row `r` holds input column `r` when flopped, `width - 1 - r` otherwise.
+        let start_row = match flop_mode {
+            FlopMode::Flop => x,
+            FlopMode::NoFlop => width - x - columns,
+        };
+        for y in (0..height).step_by(band) {
+            let rows = band.min(height - y);
+            let plane_size = columns * rows;
+            let mut chunks = scratch.chunks_exact_mut(plane_size);
+            let mut planes: [&mut [V]; CN] = core::array::from_fn(|_| chunks.next().unwrap());
+            for row in 0..rows {
+                let mut dst = plane_rows(&mut planes, &[columns; CN], row);
+                interleaver.deinterleave(
+                    &input[(y + row) * input_stride + x * CN..],
+                    &mut dst,
+                    columns,
+                );
+            }
+
+            // Output column `c` holds input row `c` when not flipped, `height - 1 - c` otherwise.
+            let start_column = match flip_mode {
+                FlipMode::NoFlip => y,
+                FlipMode::Flip => height - y - rows,
+            };
+            for ((plane, &stride), src) in output
+                .iter_mut()
+                .zip(output_strides.iter())
+                .zip(planes.iter())
+            {
+                if rows == height {
+                    executor(
+                        src,
+                        columns,
+                        &mut plane[start_row * stride..(start_row + columns) * stride],
+                        stride,
+                        columns,
+                        rows,
+                        flip_mode,
+                        flop_mode,
+                    )?;
+                    continue;
+                }
+                let tile = &mut transposed[..plane_size];
+                executor(
+                    src, columns, tile, rows, columns, rows, flip_mode, flop_mode,
+                )?;
+                for (dst, src) in plane[start_row * stride..]
+                    .chunks_mut(stride)
+                    .zip(tile.chunks_exact(rows))
+                {
+                    dst[start_column..start_column + rows].copy_from_slice(src);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Splits interleaved two channel image into two planes
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn deinterleave_2ch(
+    input: &[u8],
+    input_stride: usize,
+    output: [&mut [u8]; 2],
+    output_strides: [usize; 2],
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height
+    )
+}
+
+/// Splits interleaved two channel image into two planes
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn deinterleave_2ch16(
+    input: &[u16],
+    input_stride: usize,
+    output: [&mut [u16]; 2],
+    output_strides: [usize; 2],
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height
+    )
+}
+
+/// Splits interleaved two channel image into two planes
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn deinterleave_2ch_f32(
+    input: &[f32],
+    input_stride: usize,
+    output: [&mut [f32]; 2],
+    output_strides: [usize; 2],
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height
+    )
+}
+
+/// Splits interleaved three channel image into three planes
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn deinterleave_3ch(
+    input: &[u8],
+    input_stride: usize,
+    output: [&mut [u8]; 3],
+    output_strides: [usize; 3],
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height
+    )
+}
+
+/// Splits interleaved three channel image into three planes
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn deinterleave_3ch16(
+    input: &[u16],
+    input_stride: usize,
+    output: [&mut [u16]; 3],
+    output_strides: [usize; 3],
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height
+    )
+}
+
+/// Splits interleaved three channel image into three planes
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn deinterleave_3ch_f32(
+    input: &[f32],
+    input_stride: usize,
+    output: [&mut [f32]; 3],
+    output_strides: [usize; 3],
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height
+    )
+}
+
+/// Splits interleaved four channel image into four planes
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn deinterleave_4ch(
+    input: &[u8],
+    input_stride: usize,
+    output: [&mut [u8]; 4],
+    output_strides: [usize; 4],
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height
+    )
+}
+
+/// Splits interleaved four channel image into four planes
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn deinterleave_4ch16(
+    input: &[u16],
+    input_stride: usize,
+    output: [&mut [u16]; 4],
+    output_strides: [usize; 4],
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height
+    )
+}
+
+/// Splits interleaved four channel image into four planes
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn deinterleave_4ch_f32(
+    input: &[f32],
+    input_stride: usize,
+    output: [&mut [f32]; 4],
+    output_strides: [usize; 4],
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height
+    )
+}
+
+/// Merges two planes into interleaved two channel image
+///
+/// # Arguments
+///
+/// * `input`: Input planes
+/// * `input_strides`: Input planes strides
+/// * `output`: Interleaved output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn interleave_2ch(
+    input: [&[u8]; 2],
+    input_strides: [usize; 2],
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        interleave_impl,
+        input,
+        input_strides,
+        output,
+        output_stride,
+        width,
+        height
+    )
+}
+
+/// Merges two planes into interleaved two channel image
+///
+/// # Arguments
+///
+/// * `input`: Input planes
+/// * `input_strides`: Input planes strides
+/// * `output`: Interleaved output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn interleave_2ch16(
+    input: [&[u16]; 2],
+    input_strides: [usize; 2],
+    output: &mut [u16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        interleave_impl,
+        input,
+        input_strides,
+        output,
+        output_stride,
+        width,
+        height
+    )
+}
+
+/// Merges two planes into interleaved two channel image
+///
+/// # Arguments
+///
+/// * `input`: Input planes
+/// * `input_strides`: Input planes strides
+/// * `output`: Interleaved output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn interleave_2ch_f32(
+    input: [&[f32]; 2],
+    input_strides: [usize; 2],
+    output: &mut [f32],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        interleave_impl,
+        input,
+        input_strides,
+        output,
+        output_stride,
+        width,
+        height
+    )
+}
+
+/// Merges three planes into interleaved three channel image
+///
+/// # Arguments
+///
+/// * `input`: Input planes
+/// * `input_strides`: Input planes strides
+/// * `output`: Interleaved output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn interleave_3ch(
+    input: [&[u8]; 3],
+    input_strides: [usize; 3],
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        interleave_impl,
+        input,
+        input_strides,
+        output,
+        output_stride,
+        width,
+        height
+    )
+}
+
+/// Merges three planes into interleaved three channel image
+///
+/// # Arguments
+///
+/// * `input`: Input planes
+/// * `input_strides`: Input planes strides
+/// * `output`: Interleaved output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn interleave_3ch16(
+    input: [&[u16]; 3],
+    input_strides: [usize; 3],
+    output: &mut [u16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        interleave_impl,
+        input,
+        input_strides,
+        output,
+        output_stride,
+        width,
+        height
+    )
+}
+
+/// Merges three planes into interleaved three channel image
+///
+/// # Arguments
+///
+/// * `input`: Input planes
+/// * `input_strides`: Input planes strides
+/// * `output`: Interleaved output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn interleave_3ch_f32(
+    input: [&[f32]; 3],
+    input_strides: [usize; 3],
+    output: &mut [f32],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        interleave_impl,
+        input,
+        input_strides,
+        output,
+        output_stride,
+        width,
+        height
+    )
+}
+
+/// Merges four planes into interleaved four channel image
+///
+/// # Arguments
+///
+/// * `input`: Input planes
+/// * `input_strides`: Input planes strides
+/// * `output`: Interleaved output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn interleave_4ch(
+    input: [&[u8]; 4],
+    input_strides: [usize; 4],
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        interleave_impl,
+        input,
+        input_strides,
+        output,
+        output_stride,
+        width,
+        height
+    )
+}
+
+/// Merges four planes into interleaved four channel image
+///
+/// # Arguments
+///
+/// * `input`: Input planes
+/// * `input_strides`: Input planes strides
+/// * `output`: Interleaved output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn interleave_4ch16(
+    input: [&[u16]; 4],
+    input_strides: [usize; 4],
+    output: &mut [u16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        interleave_impl,
+        input,
+        input_strides,
+        output,
+        output_stride,
+        width,
+        height
+    )
+}
+
+/// Merges four planes into interleaved four channel image
+///
+/// # Arguments
+///
+/// * `input`: Input planes
+/// * `input_strides`: Input planes strides
+/// * `output`: Interleaved output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn interleave_4ch_f32(
+    input: [&[f32]; 4],
+    input_strides: [usize; 4],
+    output: &mut [f32],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        interleave_impl,
+        input,
+        input_strides,
+        output,
+        output_stride,
+        width,
+        height
+    )
+}
+
+/// Performs transposition of interleaved two channel image into two planes in one pass
+///
+/// Output planes are `height` wide and `width` tall, e.g. rotation by 90 degrees
+/// clockwise is [FlipMode::Flip] with [FlopMode::Flop].
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_deinterleave_2ch(
+    input: &[u8],
+    input_stride: usize,
+    output: [&mut [u8]; 2],
+    output_strides: [usize; 2],
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        transpose_deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_plane8_chunked
+    )
+}
+
+/// Performs transposition of interleaved two channel image into two planes in one pass
+///
+/// Output planes are `height` wide and `width` tall, e.g. rotation by 90 degrees
+/// clockwise is [FlipMode::Flip] with [FlopMode::Flop].
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_deinterleave_2ch16(
+    input: &[u16],
+    input_stride: usize,
+    output: [&mut [u16]; 2],
+    output_strides: [usize; 2],
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        transpose_deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_plane16_chunked
+    )
+}
+
+/// Performs transposition of interleaved two channel image into two planes in one pass
+///
+/// Output planes are `height` wide and `width` tall, e.g. rotation by 90 degrees
+/// clockwise is [FlipMode::Flip] with [FlopMode::Flop].
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_deinterleave_2ch_f32(
+    input: &[f32],
+    input_stride: usize,
+    output: [&mut [f32]; 2],
+    output_strides: [usize; 2],
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        transpose_deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        execute_f32_plane
+    )
+}
+
+/// Performs transposition of interleaved three channel image into three planes in one pass
+///
+/// Output planes are `height` wide and `width` tall, e.g. rotation by 90 degrees
+/// clockwise is [FlipMode::Flip] with [FlopMode::Flop].
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_deinterleave_3ch(
+    input: &[u8],
+    input_stride: usize,
+    output: [&mut [u8]; 3],
+    output_strides: [usize; 3],
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        transpose_deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_plane8_chunked
+    )
+}
+
+/// Performs transposition of interleaved three channel image into three planes in one pass
+///
+/// Output planes are `height` wide and `width` tall, e.g. rotation by 90 degrees
+/// clockwise is [FlipMode::Flip] with [FlopMode::Flop].
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_deinterleave_3ch16(
+    input: &[u16],
+    input_stride: usize,
+    output: [&mut [u16]; 3],
+    output_strides: [usize; 3],
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        transpose_deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_plane16_chunked
+    )
+}
+
+/// Performs transposition of interleaved three channel image into three planes in one pass
+///
+/// Output planes are `height` wide and `width` tall, e.g. rotation by 90 degrees
+/// clockwise is [FlipMode::Flip] with [FlopMode::Flop].
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_deinterleave_3ch_f32(
+    input: &[f32],
+    input_stride: usize,
+    output: [&mut [f32]; 3],
+    output_strides: [usize; 3],
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        transpose_deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        execute_f32_plane
+    )
+}
+
+/// Performs transposition of interleaved four channel image into four planes in one pass
+///
+/// Output planes are `height` wide and `width` tall, e.g. rotation by 90 degrees
+/// clockwise is [FlipMode::Flip] with [FlopMode::Flop].
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_deinterleave_4ch(
+    input: &[u8],
+    input_stride: usize,
+    output: [&mut [u8]; 4],
+    output_strides: [usize; 4],
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        transpose_deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_plane8_chunked
+    )
+}
+
+/// Performs transposition of interleaved four channel image into four planes in one pass
+///
+/// Output planes are `height` wide and `width` tall, e.g. rotation by 90 degrees
+/// clockwise is [FlipMode::Flip] with [FlopMode::Flop].
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_deinterleave_4ch16(
+    input: &[u16],
+    input_stride: usize,
+    output: [&mut [u16]; 4],
+    output_strides: [usize; 4],
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        transpose_deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_plane16_chunked
+    )
+}
+
+/// Performs transposition of interleaved four channel image into four planes in one pass
+///
+/// Output planes are `height` wide and `width` tall, e.g. rotation by 90 degrees
+/// clockwise is [FlipMode::Flip] with [FlopMode::Flop].
+///
+/// # Arguments
+///
+/// * `input`: Interleaved input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output planes
+/// * `output_strides`: Output planes strides
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_deinterleave_4ch_f32(
+    input: &[f32],
+    input_stride: usize,
+    output: [&mut [f32]; 4],
+    output_strides: [usize; 4],
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        transpose_deinterleave_impl,
+        input,
+        input_stride,
+        output,
+        output_strides,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        execute_f32_plane
+    )
+}
+
+/// Reinterprets planes as slices of `U`, returns `None` when
+/// alignment of `V` doesn't allow it.
+fn cast_planes<V: Pod, U: Pod, const CN: usize>(planes: [&mut [V]; CN]) -> Option<[&mut [U]; CN]> {
+    if planes
+        .iter()
+        .any(|plane| bytemuck::try_cast_slice::<V, U>(plane).is_err())
+    {
+        return None;
+    }
+    Some(planes.map(|plane| bytemuck::cast_slice_mut::<V, U>(plane)))
+}
+
+fn deinterleave_row_dispatch<V: InterleaveSample, const CN: usize>(
+    input: &[V],
+    output: [&mut [V]; CN],
+    width: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        deinterleave_impl,
+        input,
+        width * CN,
+        output,
+        [width; CN],
+        width,
+        1
+    )
+}
+
+fn interleave_row_dispatch<V: InterleaveSample, const CN: usize>(
+    input: [&[V]; CN],
+    output: &mut [V],
+    width: usize,
+) -> Result<(), TransposeError> {
+    dispatch_interleaver!(
+        interleave_impl,
+        input,
+        [width; CN],
+        output,
+        width * CN,
+        width,
+        1
+    )
+}
+
+fn deinterleave_row_as<V: Pod, U: Pod + InterleaveSample, const CN: usize>(
+    input: &[V],
+    output: [&mut [V]; CN],
+    width: usize,
+) -> Option<Result<(), TransposeError>> {
+    let input = bytemuck::try_cast_slice::<V, U>(input).ok()?;
+    let output = cast_planes::<V, U, CN>(output)?;
+    Some(deinterleave_row_dispatch(input, output, width))
+}
+
+fn interleave_row_as<V: Pod, U: Pod + InterleaveSample, const CN: usize>(
+    input: [&[V]; CN],
+    output: &mut [V],
+    width: usize,
+) -> Option<Result<(), TransposeError>> {
+    if input
+        .iter()
+        .any(|plane| bytemuck::try_cast_slice::<V, U>(plane).is_err())
+    {
+        return None;
+    }
+    let input = input.map(|plane| bytemuck::cast_slice::<V, U>(plane));
+    let output = bytemuck::try_cast_slice_mut::<V, U>(output).ok()?;
+    Some(interleave_row_dispatch(input, output, width))
+}
+
+/// Splits `width` interleaved `Pod` pixels into `CN` planes with rows converters
+/// of the unsigned integer type of the same size.
+///
+/// Evaluates to `None` when size isn't 1, 2 or 4 bytes or slices
+/// aren't aligned enough for the integer type.
+pub(crate) fn deinterleave_pod_row<V: Pod, const CN: usize>(
+    input: &[V],
+    output: [&mut [V]; CN],
+    width: usize,
+) -> Option<Result<(), TransposeError>> {
+    match size_of::<V>() {
+        1 => deinterleave_row_as::<V, u8, CN>(input, output, width),
+        2 => deinterleave_row_as::<V, u16, CN>(input, output, width),
+        4 => deinterleave_row_as::<V, u32, CN>(input, output, width),
+        _ => None,
+    }
+}
+
+/// Merges `CN` planes into `width` interleaved `Pod` pixels with rows converters
+/// of the unsigned integer type of the same size.
+///
+/// Evaluates to `None` when size isn't 1, 2 or 4 bytes or slices
+/// aren't aligned enough for the integer type.
+pub(crate) fn interleave_pod_row<V: Pod, const CN: usize>(
+    input: [&[V]; CN],
+    output: &mut [V],
+    width: usize,
+) -> Option<Result<(), TransposeError>> {
+    match size_of::<V>() {
+        1 => interleave_row_as::<V, u8, CN>(input, output, width),
+        2 => interleave_row_as::<V, u16, CN>(input, output, width),
+        4 => interleave_row_as::<V, u32, CN>(input, output, width),
+        _ => None,
+    }
+}
@@ -0,0 +1,205 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+//! Transposition of single fixed size blocks stored row by row.
+//!
+//! Blocks are transposed in registers with the best kernel available on the CPU,
+//! selected at runtime, or with generic copy when there is none, e.g. to use
+//! inside own loops over blocks.
+#![forbid(unsafe_code)]
+use crate::batch::transpose_square_batch;
+use bytemuck::Pod;
+
+#[inline]
+fn transpose_block<T: Pod, const LEN: usize>(src: &[T; LEN], n: usize) -> [T; LEN] {
+    let mut dst = [T::zeroed(); LEN];
+    if !transpose_square_batch(src, &mut dst, n) {
+        for (y, row) in src.chunks_exact(n).enumerate() {
+            for (x, &v) in row.iter().enumerate() {
+                dst[x * n + y] = v;
+            }
+        }
+    }
+    dst
+}
+
+/// Performs transposition of 8x8 block of bytes
+///
+/// # Arguments
+///
+/// * `src`: Block stored row by row
+///
+/// returns: Transposed block stored row by row
+///
+pub fn transpose_8x8_u8(src: &[u8; 64]) -> [u8; 64] {
+    transpose_block(src, 8)
+}
+
+/// Performs transposition of 16x16 block of bytes
+///
+/// # Arguments
+///
+/// * `src`: Block stored row by row
+///
+/// returns: Transposed block stored row by row
+///
+pub fn transpose_16x16_u8(src: &[u8; 256]) -> [u8; 256] {
+    transpose_block(src, 16)
+}
+
+/// Performs transposition of 4x4 block of `u16`
+///
+/// # Arguments
+///
+/// * `src`: Block stored row by row
+///
+/// returns: Transposed block stored row by row
+///
+pub fn transpose_4x4_u16(src: &[u16; 16]) -> [u16; 16] {
+    transpose_block(src, 4)
+}
+
+/// Performs transposition of 8x8 block of `u16`
+///
+/// # Arguments
+///
+/// * `src`: Block stored row by row
+///
+/// returns: Transposed block stored row by row
+///
+pub fn transpose_8x8_u16(src: &[u16; 64]) -> [u16; 64] {
+    transpose_block(src, 8)
+}
+
+/// Performs transposition of 4x4 block of `u32`
+///
+/// # Arguments
+///
+/// * `src`: Block stored row by row
+///
+/// returns: Transposed block stored row by row
+///
+pub fn transpose_4x4_u32(src: &[u32; 16]) -> [u32; 16] {
+    transpose_block(src, 4)
+}
+
+/// Performs transposition of 8x8 block of `u32`
+///
+/// # Arguments
+///
+/// * `src`: Block stored row by row
+///
+/// returns: Transposed block stored row by row
+///
+pub fn transpose_8x8_u32(src: &[u32; 64]) -> [u32; 64] {
+    transpose_block(src, 8)
+}
+
+/// Performs transposition of 16x16 block of `u32`
+///
+/// # Arguments
+///
+/// * `src`: Block stored row by row
+///
+/// returns: Transposed block stored row by row
+///
+pub fn transpose_16x16_u32(src: &[u32; 256]) -> [u32; 256] {
+    transpose_block(src, 16)
+}
+
+/// Performs transposition of 4x4 block of `f32`
+///
+/// # Arguments
+///
+/// * `src`: Block stored row by row
+///
+/// returns: Transposed block stored row by row
+///
+pub fn transpose_4x4_f32(src: &[f32; 16]) -> [f32; 16] {
+    transpose_block(src, 4)
+}
+
+/// Performs transposition of 8x8 block of `f32`
+///
+/// # Arguments
+///
+/// * `src`: Block stored row by row
+///
+/// returns: Transposed block stored row by row
+///
+pub fn transpose_8x8_f32(src: &[f32; 64]) -> [f32; 64] {
+    transpose_block(src, 8)
+}
+
+/// Performs transposition of 16x16 block of `f32`
+///
+/// # Arguments
+///
+/// * `src`: Block stored row by row
+///
+/// returns: Transposed block stored row by row
+///
+pub fn transpose_16x16_f32(src: &[f32; 256]) -> [f32; 256] {
+    transpose_block(src, 16)
+}
+
+/// Performs transposition of 2x2 block of `f64`
+///
+/// # Arguments
+///
+/// * `src`: Block stored row by row
+///
+/// returns: Transposed block stored row by row
+///
+pub fn transpose_2x2_f64(src: &[f64; 4]) -> [f64; 4] {
+    transpose_block(src, 2)
+}
+
+/// Performs transposition of 4x4 block of `f64`
+///
+/// # Arguments
+///
+/// * `src`: Block stored row by row
+///
+/// returns: Transposed block stored row by row
+///
+pub fn transpose_4x4_f64(src: &[f64; 16]) -> [f64; 16] {
+    transpose_block(src, 4)
+}
+
+/// Performs transposition of 8x8 block of `f64`
+///
+/// # Arguments
+///
+/// * `src`: Block stored row by row
+///
+/// returns: Transposed block stored row by row
+///
+pub fn transpose_8x8_f64(src: &[f64; 64]) -> [f64; 64] {
+    transpose_block(src, 8)
+}
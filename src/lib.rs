@@ -27,6 +27,7 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 #![allow(clippy::too_many_arguments)]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(not(feature = "unsafe"), forbid(unsafe_code))]
 #![allow(stable_features, unused_features)]
 #![cfg_attr(
@@ -51,24 +52,47 @@
     feature(stdarch_x86_avx512)
 )]
 #![deny(unreachable_pub)]
+extern crate alloc;
 extern crate core;
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+mod aos;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 mod avx;
 #[cfg(all(target_arch = "x86_64", feature = "nightly_avx512"))]
 mod avx512;
+mod batch;
+mod bits;
 mod cbcr8;
+#[cfg(feature = "num-complex")]
+mod complex;
 mod flip;
 mod float32_cbcr_invoker;
 mod float32_invoker;
+mod float64_cbcr_invoker;
+mod float64_invoker;
+#[cfg(feature = "half")]
+mod float_16;
 mod float_32;
+mod float_64;
 mod flop;
+mod interleave;
+pub mod kernels;
 #[cfg(all(target_arch = "aarch64", feature = "unsafe", feature = "neon"))]
 mod neon;
+mod orientation;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod permute;
 mod plane16;
 mod plane8;
+mod planes;
 mod rgba16;
 mod rgba8;
+mod roll;
 mod rotate180;
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
@@ -76,34 +100,95 @@ mod rotate180;
     feature = "sse"
 ))]
 mod sse;
+mod tile;
 mod transpose_arbitrary;
 mod transpose_arbitrary_group;
 mod unsigned_16;
 mod unsigned_8;
 mod utils;
+mod yuv16;
+mod yuv420;
+mod yuv422;
 
+pub use aos::{aos_to_soa, soa_to_aos};
+pub use batch::{transpose_array, transpose_batch};
+pub use bits::{
+    flip_bits, flip_packed, flop_bits, flop_packed, rotate180_bits, rotate180_packed,
+    transpose_bits, transpose_packed, BitOrder, PackedDepth,
+};
+#[cfg(feature = "num-complex")]
+pub use complex::{
+    conjugate_transpose_complex32, conjugate_transpose_complex64, transpose_complex32,
+    transpose_complex64,
+};
 pub use flip::{
     flip_arbitrary, flip_plane, flip_plane16, flip_plane16_with_alpha, flip_plane_f32,
-    flip_plane_f32_with_alpha, flip_plane_with_alpha, flip_rgb, flip_rgb16, flip_rgb_f32,
-    flip_rgba, flip_rgba16, flip_rgba_f32,
+    flip_plane_f32_with_alpha, flip_plane_f64, flip_plane_with_alpha, flip_pod, flip_rgb,
+    flip_rgb16, flip_rgb_f32, flip_rgba, flip_rgba16, flip_rgba_f32,
+};
+#[cfg(feature = "half")]
+pub use float_16::{
+    flip_plane_f16, flip_plane_with_alpha_f16, flip_rgb_f16, flip_rgba_f16, flop_plane_f16,
+    flop_plane_with_alpha_f16, flop_rgb_f16, flop_rgba_f16, rotate180_plane_f16,
+    rotate180_plane_with_alpha_f16, rotate180_rgb_f16, rotate180_rgba_f16, transpose_plane_f16,
+    transpose_plane_with_alpha_f16, transpose_rgb_f16, transpose_rgba_f16,
 };
 pub use float_32::{
     transpose_plane_f32, transpose_plane_f32_with_alpha, transpose_rgb_f32, transpose_rgba_f32,
 };
+#[cfg(feature = "rayon")]
+pub use float_32::{
+    transpose_plane_f32_par, transpose_plane_f32_with_alpha_par, transpose_rgb_f32_par,
+    transpose_rgba_f32_par,
+};
+pub use float_64::transpose_plane_f64;
+#[cfg(feature = "rayon")]
+pub use float_64::transpose_plane_f64_par;
 pub use flop::{
     flop_arbitrary, flop_plane, flop_plane16, flop_plane16_with_alpha, flop_plane_f32,
-    flop_plane_f32_with_alpha, flop_plane_with_alpha, flop_rgb, flop_rgb16, flop_rgb_f32,
-    flop_rgba, flop_rgba16, flop_rgba_f32,
+    flop_plane_f32_with_alpha, flop_plane_f64, flop_plane_with_alpha, flop_pod, flop_rgb,
+    flop_rgb16, flop_rgb_f32, flop_rgba, flop_rgba16, flop_rgba_f32,
+};
+pub use interleave::{
+    deinterleave_2ch, deinterleave_2ch16, deinterleave_2ch_f32, deinterleave_3ch,
+    deinterleave_3ch16, deinterleave_3ch_f32, deinterleave_4ch, deinterleave_4ch16,
+    deinterleave_4ch_f32, interleave_2ch, interleave_2ch16, interleave_2ch_f32, interleave_3ch,
+    interleave_3ch16, interleave_3ch_f32, interleave_4ch, interleave_4ch16, interleave_4ch_f32,
+    transpose_deinterleave_2ch, transpose_deinterleave_2ch16, transpose_deinterleave_2ch_f32,
+    transpose_deinterleave_3ch, transpose_deinterleave_3ch16, transpose_deinterleave_3ch_f32,
+    transpose_deinterleave_4ch, transpose_deinterleave_4ch16, transpose_deinterleave_4ch_f32,
 };
+pub use permute::permute_axes;
+pub use planes::{orient_planes, orient_planes16, orient_planes_f32};
+pub use roll::{fftshift_2d, ifftshift_2d, roll_2d, transpose_fftshift_2d, transpose_ifftshift_2d};
 pub use rotate180::{
     rotate180_arbitrary, rotate180_plane, rotate180_plane16, rotate180_plane16_with_alpha,
-    rotate180_plane_f32, rotate180_plane_f32_with_alpha, rotate180_plane_with_alpha, rotate180_rgb,
-    rotate180_rgb16, rotate180_rgb_f32, rotate180_rgba, rotate180_rgba16, rotate180_rgba_f32,
+    rotate180_plane_f32, rotate180_plane_f32_with_alpha, rotate180_plane_f64,
+    rotate180_plane_with_alpha, rotate180_pod, rotate180_rgb, rotate180_rgb16, rotate180_rgb_f32,
+    rotate180_rgba, rotate180_rgba16, rotate180_rgba_f32,
 };
-pub use transpose_arbitrary::transpose_arbitrary;
+pub use tile::{
+    transpose_tiles, transpose_tiles_2d, TransposeTile, TransposeTiles, TransposeTiles2d,
+};
+pub use transpose_arbitrary::{transpose_arbitrary, transpose_pod};
 pub use transpose_arbitrary_group::transpose_arbitrary_grouped;
 pub use unsigned_16::{
     transpose_plane16, transpose_plane16_with_alpha, transpose_rgb16, transpose_rgba16,
 };
+#[cfg(feature = "rayon")]
+pub use unsigned_16::{
+    transpose_plane16_par, transpose_plane16_with_alpha_par, transpose_rgb16_par,
+    transpose_rgba16_par,
+};
 pub use unsigned_8::{transpose_plane, transpose_plane_with_alpha, transpose_rgb, transpose_rgba};
-pub use utils::{FlipMode, FlopMode, TransposeError};
+#[cfg(feature = "rayon")]
+pub use unsigned_8::{
+    transpose_plane_par, transpose_plane_with_alpha_par, transpose_rgb_par, transpose_rgba_par,
+};
+pub use utils::{BitDepthCheck, ChromaSiting, FlipMode, FlopMode, Orientation, TransposeError};
+pub use yuv16::{rotate_i010, rotate_i210, rotate_p010, rotate_p016};
+pub use yuv420::{
+    rotate_i420, rotate_i420_with_siting, rotate_nv12, rotate_nv12_to_i420, rotate_nv21,
+    rotate_nv21_to_i420,
+};
+pub use yuv422::{rotate_i422, Rotated422Layout};
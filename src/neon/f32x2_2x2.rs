@@ -28,7 +28,7 @@
  */
 
 use crate::neon::utils::vrev128q_f64;
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 #[inline(always)]
 pub(crate) unsafe fn neon_transpose_f32x2_2x2_impl<const FLIP: bool>(
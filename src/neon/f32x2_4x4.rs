@@ -27,7 +27,7 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 use crate::neon::f32x2_2x2::neon_transpose_f32x2_2x2_impl;
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 #[inline]
 pub(crate) fn neon_transpose_f32x2_4x4<const FLOP: bool, const FLIP: bool>(
@@ -60,8 +60,8 @@ pub(crate) fn neon_transpose_f32x2_4x4<const FLOP: bool, const FLIP: bool>(
         let mut q3 = neon_transpose_f32x2_2x2_impl::<FLIP>(float32x4x2_t(r5, r7));
 
         if FLIP {
-            std::mem::swap(&mut q0, &mut q2);
-            std::mem::swap(&mut q1, &mut q3);
+            core::mem::swap(&mut q0, &mut q2);
+            core::mem::swap(&mut q1, &mut q3);
         }
 
         if FLOP {
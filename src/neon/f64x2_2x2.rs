@@ -0,0 +1,63 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use core::arch::aarch64::*;
+
+#[inline]
+pub(crate) fn neon_transpose_f64x2_2x2<const FLOP: bool, const FLIP: bool>(
+    src: &[f64],
+    src_stride: usize,
+    dst: &mut [f64],
+    dst_stride: usize,
+) {
+    unsafe {
+        // Every pixel is a whole 128 bit register, so transposition only
+        // reorders loads and stores.
+        let p00 = vld1q_f64(src.get_unchecked(0..).as_ptr());
+        let p01 = vld1q_f64(src.get_unchecked(2..).as_ptr());
+        let p10 = vld1q_f64(src.get_unchecked(src_stride..).as_ptr());
+        let p11 = vld1q_f64(src.get_unchecked(src_stride + 2..).as_ptr());
+
+        let (c00, c01, c10, c11) = if FLIP {
+            (p10, p00, p11, p01)
+        } else {
+            (p00, p10, p01, p11)
+        };
+
+        let (row0, row1) = if FLOP {
+            (0, dst_stride)
+        } else {
+            (dst_stride, 0)
+        };
+
+        vst1q_f64(dst.get_unchecked_mut(row0..).as_mut_ptr(), c00);
+        vst1q_f64(dst.get_unchecked_mut(row0 + 2..).as_mut_ptr(), c01);
+        vst1q_f64(dst.get_unchecked_mut(row1..).as_mut_ptr(), c10);
+        vst1q_f64(dst.get_unchecked_mut(row1 + 2..).as_mut_ptr(), c11);
+    }
+}
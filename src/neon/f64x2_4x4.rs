@@ -0,0 +1,62 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use core::arch::aarch64::*;
+
+#[inline]
+pub(crate) fn neon_transpose_f64x2_4x4<const FLOP: bool, const FLIP: bool>(
+    src: &[f64],
+    src_stride: usize,
+    dst: &mut [f64],
+    dst_stride: usize,
+) {
+    unsafe {
+        // Every pixel is a whole 128 bit register, so transposition only
+        // reorders loads and stores. Four pixels make a whole cache line
+        // of each destination row.
+        let mut rows = [[vdupq_n_f64(0.); 4]; 4];
+        for (i, row) in rows.iter_mut().enumerate() {
+            let src = src.get_unchecked(i * src_stride..);
+            for (j, v) in row.iter_mut().enumerate() {
+                *v = vld1q_f64(src.get_unchecked(j * 2..).as_ptr());
+            }
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            let dst_col = if FLIP { 3 - i } else { i };
+            for (j, &v) in row.iter().enumerate() {
+                let dst_row = if FLOP { j } else { 3 - j };
+                vst1q_f64(
+                    dst.get_unchecked_mut(dst_row * dst_stride + dst_col * 2..)
+                        .as_mut_ptr(),
+                    v,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,203 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use core::arch::aarch64::*;
+
+macro_rules! define_neon_interleave {
+    ($v:ty, $lanes:expr, $deinterleave:ident, $interleave:ident,
+     $ld1:ident, $st1:ident, $ld2:ident, $ld3:ident, $ld4:ident,
+     $st2:ident, $st3:ident, $st4:ident, $x2:ident, $x3:ident, $x4:ident) => {
+        /// Splits `width` pixels of `CN` interleaved channels into `CN` planes, `CN` is 2, 3 or 4
+        #[inline]
+        pub(crate) fn $deinterleave<const CN: usize>(
+            src: &[$v],
+            dst: &mut [&mut [$v]; CN],
+            width: usize,
+        ) {
+            let src = &src[..width * CN];
+            let mut dst = dst.each_mut().map(|plane| &mut plane[..width]);
+            let mut x = 0usize;
+            unsafe {
+                while x + $lanes <= width {
+                    let ptr = src.get_unchecked(x * CN..).as_ptr();
+                    match CN {
+                        2 => {
+                            let v = $ld2(ptr);
+                            $st1(dst[0].get_unchecked_mut(x..).as_mut_ptr(), v.0);
+                            $st1(dst[1].get_unchecked_mut(x..).as_mut_ptr(), v.1);
+                        }
+                        3 => {
+                            let v = $ld3(ptr);
+                            $st1(dst[0].get_unchecked_mut(x..).as_mut_ptr(), v.0);
+                            $st1(dst[1].get_unchecked_mut(x..).as_mut_ptr(), v.1);
+                            $st1(dst[2].get_unchecked_mut(x..).as_mut_ptr(), v.2);
+                        }
+                        4 => {
+                            let v = $ld4(ptr);
+                            $st1(dst[0].get_unchecked_mut(x..).as_mut_ptr(), v.0);
+                            $st1(dst[1].get_unchecked_mut(x..).as_mut_ptr(), v.1);
+                            $st1(dst[2].get_unchecked_mut(x..).as_mut_ptr(), v.2);
+                            $st1(dst[3].get_unchecked_mut(x..).as_mut_ptr(), v.3);
+                        }
+                        _ => unreachable!(),
+                    }
+                    x += $lanes;
+                }
+            }
+
+            for (x, px) in src.chunks_exact(CN).enumerate().skip(x) {
+                for (plane, &v) in dst.iter_mut().zip(px) {
+                    plane[x] = v;
+                }
+            }
+        }
+
+        /// Merges `CN` planes into `width` pixels of `CN` interleaved channels, `CN` is 2, 3 or 4
+        #[inline]
+        pub(crate) fn $interleave<const CN: usize>(
+            src: &[&[$v]; CN],
+            dst: &mut [$v],
+            width: usize,
+        ) {
+            let src = src.map(|plane| &plane[..width]);
+            let dst = &mut dst[..width * CN];
+            let mut x = 0usize;
+            unsafe {
+                while x + $lanes <= width {
+                    let ptr = dst.get_unchecked_mut(x * CN..).as_mut_ptr();
+                    match CN {
+                        2 => $st2(
+                            ptr,
+                            $x2(
+                                $ld1(src[0].get_unchecked(x..).as_ptr()),
+                                $ld1(src[1].get_unchecked(x..).as_ptr()),
+                            ),
+                        ),
+                        3 => $st3(
+                            ptr,
+                            $x3(
+                                $ld1(src[0].get_unchecked(x..).as_ptr()),
+                                $ld1(src[1].get_unchecked(x..).as_ptr()),
+                                $ld1(src[2].get_unchecked(x..).as_ptr()),
+                            ),
+                        ),
+                        4 => $st4(
+                            ptr,
+                            $x4(
+                                $ld1(src[0].get_unchecked(x..).as_ptr()),
+                                $ld1(src[1].get_unchecked(x..).as_ptr()),
+                                $ld1(src[2].get_unchecked(x..).as_ptr()),
+                                $ld1(src[3].get_unchecked(x..).as_ptr()),
+                            ),
+                        ),
+                        _ => unreachable!(),
+                    }
+                    x += $lanes;
+                }
+            }
+
+            for (x, px) in dst.chunks_exact_mut(CN).enumerate().skip(x) {
+                for (v, plane) in px.iter_mut().zip(src.iter()) {
+                    *v = plane[x];
+                }
+            }
+        }
+    };
+}
+
+define_neon_interleave!(
+    u8,
+    16,
+    neon_deinterleave_row_u8,
+    neon_interleave_row_u8,
+    vld1q_u8,
+    vst1q_u8,
+    vld2q_u8,
+    vld3q_u8,
+    vld4q_u8,
+    vst2q_u8,
+    vst3q_u8,
+    vst4q_u8,
+    uint8x16x2_t,
+    uint8x16x3_t,
+    uint8x16x4_t
+);
+
+define_neon_interleave!(
+    u16,
+    8,
+    neon_deinterleave_row_u16,
+    neon_interleave_row_u16,
+    vld1q_u16,
+    vst1q_u16,
+    vld2q_u16,
+    vld3q_u16,
+    vld4q_u16,
+    vst2q_u16,
+    vst3q_u16,
+    vst4q_u16,
+    uint16x8x2_t,
+    uint16x8x3_t,
+    uint16x8x4_t
+);
+
+define_neon_interleave!(
+    u32,
+    4,
+    neon_deinterleave_row_u32,
+    neon_interleave_row_u32,
+    vld1q_u32,
+    vst1q_u32,
+    vld2q_u32,
+    vld3q_u32,
+    vld4q_u32,
+    vst2q_u32,
+    vst3q_u32,
+    vst4q_u32,
+    uint32x4x2_t,
+    uint32x4x3_t,
+    uint32x4x4_t
+);
+
+define_neon_interleave!(
+    f32,
+    4,
+    neon_deinterleave_row_f32,
+    neon_interleave_row_f32,
+    vld1q_f32,
+    vst1q_f32,
+    vld2q_f32,
+    vld3q_f32,
+    vld4q_f32,
+    vst2q_f32,
+    vst3q_f32,
+    vst4q_f32,
+    float32x4x2_t,
+    float32x4x3_t,
+    float32x4x4_t
+);
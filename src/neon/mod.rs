@@ -28,6 +28,9 @@
  */
 mod f32x2_2x2;
 mod f32x2_4x4;
+mod f64x2_2x2;
+mod f64x2_4x4;
+mod interleave;
 mod u16_4x4;
 mod u16_8x8;
 mod u16x4_2x2;
@@ -37,6 +40,7 @@ mod u8_8x8;
 mod u8x2_4x4;
 mod u8x2_8x8;
 mod utils;
+mod x2_f64;
 mod x4_f32;
 mod x4_u32;
 mod x8_f32;
@@ -44,6 +48,13 @@ mod x8_u32;
 
 pub(crate) use f32x2_2x2::neon_transpose_f32x2_2x2;
 pub(crate) use f32x2_4x4::neon_transpose_f32x2_4x4;
+pub(crate) use f64x2_2x2::neon_transpose_f64x2_2x2;
+pub(crate) use f64x2_4x4::neon_transpose_f64x2_4x4;
+pub(crate) use interleave::{
+    neon_deinterleave_row_f32, neon_deinterleave_row_u16, neon_deinterleave_row_u32,
+    neon_deinterleave_row_u8, neon_interleave_row_f32, neon_interleave_row_u16,
+    neon_interleave_row_u32, neon_interleave_row_u8,
+};
 pub(crate) use u16_4x4::neon_transpose_4x4_u16;
 pub(crate) use u16_8x8::neon_transpose_8x8_u16;
 pub(crate) use u16x4_2x2::neon_transpose_u16x4_2x2;
@@ -52,6 +63,7 @@ pub(crate) use u8_16x16::neon_transpose_u8_16x16;
 pub(crate) use u8_8x8::neon_transpose_u8_8x8;
 pub(crate) use u8x2_4x4::neon_transpose_u8x2_4x4;
 pub(crate) use u8x2_8x8::neon_transpose_u8x2_8x8;
+pub(crate) use x2_f64::neon_transpose_2x2_f64;
 pub(crate) use x4_f32::neon_transpose_4x4_f32;
 pub(crate) use x4_u32::neon_transpose_4x4_u8x4;
 pub(crate) use x8_f32::neon_transpose_8x8_f32;
@@ -27,7 +27,7 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 #[inline(always)]
 pub(crate) unsafe fn neon_transpose_u16_4x4_impl<const FLIP: bool>(
@@ -28,7 +28,7 @@
  */
 
 use crate::neon::utils::{vrev128q_u64, xvld1q_u16_u64, xvst1q_u16_u64};
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 #[inline(always)]
 pub(crate) unsafe fn neon_transpose_u64_2x2_impl<const FLIP: bool>(
@@ -28,7 +28,7 @@
  */
 use crate::neon::u16x4_2x2::neon_transpose_u64_2x2_impl;
 use crate::neon::utils::{xvld1q_u16_u64, xvst1q_u16_u64};
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 #[inline]
 pub(crate) fn neon_transpose_u16x4_4x4<const FLOP: bool, const FLIP: bool>(
@@ -61,8 +61,8 @@ pub(crate) fn neon_transpose_u16x4_4x4<const FLOP: bool, const FLIP: bool>(
         let mut q3 = neon_transpose_u64_2x2_impl::<FLIP>(uint64x2x2_t(r5, r7));
 
         if FLIP {
-            std::mem::swap(&mut q0, &mut q2);
-            std::mem::swap(&mut q1, &mut q3);
+            core::mem::swap(&mut q0, &mut q2);
+            core::mem::swap(&mut q1, &mut q3);
         }
 
         if FLOP {
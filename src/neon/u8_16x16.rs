@@ -28,7 +28,7 @@
  */
 
 use crate::neon::utils::{vrev128_u8, vtrnq_u64_to_u16};
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 #[inline(always)]
 unsafe fn neon_transpose_16x16_impl<const FLIP: bool>(
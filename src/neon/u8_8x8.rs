@@ -27,7 +27,7 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 #[inline(always)]
 unsafe fn neon_transpose_8x8_impl<const FLIP: bool>(
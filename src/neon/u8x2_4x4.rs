@@ -27,7 +27,7 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 use crate::neon::utils::{xvld1_u8_u16, xvst1_u8_u16};
-use std::arch::aarch64::uint16x4x4_t;
+use core::arch::aarch64::uint16x4x4_t;
 
 #[inline]
 pub(crate) fn neon_transpose_u8x2_4x4<const FLOP: bool, const FLIP: bool>(
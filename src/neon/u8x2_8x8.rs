@@ -27,7 +27,7 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 use crate::neon::utils::{xvld1q_u8_u16, xvst1q_u8_u16};
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 #[inline(always)]
 pub(crate) fn neon_transpose_u8x2_8x8<const FLOP: bool, const FLIP: bool>(
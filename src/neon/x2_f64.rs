@@ -0,0 +1,72 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use core::arch::aarch64::*;
+
+#[inline(always)]
+pub(crate) unsafe fn neon_transpose_2x2_impl_f64<const FLIP: bool>(
+    v0: float64x2x2_t,
+) -> float64x2x2_t {
+    // Swap 64 bit elements. Goes from:
+    // a0: 00 01
+    // a1: 10 11
+    // to:
+    // b0: 00 10
+    // b1: 01 11
+    let b0 = vtrn1q_f64(v0.0, v0.1);
+    let b1 = vtrn2q_f64(v0.0, v0.1);
+
+    if FLIP {
+        float64x2x2_t(vextq_f64::<1>(b0, b0), vextq_f64::<1>(b1, b1))
+    } else {
+        float64x2x2_t(b0, b1)
+    }
+}
+
+#[inline]
+pub(crate) fn neon_transpose_2x2_f64<const FLOP: bool, const FLIP: bool>(
+    src: &[f64],
+    src_stride: usize,
+    dst: &mut [f64],
+    dst_stride: usize,
+) {
+    unsafe {
+        let row0 = vld1q_f64(src.get_unchecked(0..).as_ptr());
+        let row1 = vld1q_f64(src.get_unchecked(src_stride..).as_ptr());
+
+        let v0 = neon_transpose_2x2_impl_f64::<FLIP>(float64x2x2_t(row0, row1));
+
+        if FLOP {
+            vst1q_f64(dst.get_unchecked_mut(0..).as_mut_ptr(), v0.0);
+            vst1q_f64(dst.get_unchecked_mut(dst_stride..).as_mut_ptr(), v0.1);
+        } else {
+            vst1q_f64(dst.get_unchecked_mut(dst_stride..).as_mut_ptr(), v0.0);
+            vst1q_f64(dst.get_unchecked_mut(0..).as_mut_ptr(), v0.1);
+        }
+    }
+}
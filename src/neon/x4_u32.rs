@@ -28,7 +28,7 @@
  */
 
 use crate::neon::utils::{vrev128_u32, vtrnq_s64_to_u32, xvld1q_u8_u32, xvst1q_u8_u32};
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 #[inline(always)]
 pub(crate) unsafe fn neon_transpose_4x4_impl<const FLIP: bool>(v0: uint32x4x4_t) -> uint32x4x4_t {
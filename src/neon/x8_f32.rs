@@ -27,7 +27,7 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 use crate::neon::x4_f32::neon_transpose_4x4_impl_f32;
-use std::arch::aarch64::*;
+use core::arch::aarch64::*;
 
 #[inline]
 pub(crate) fn neon_transpose_8x8_f32<const FLOP: bool, const FLIP: bool>(
@@ -63,8 +63,8 @@ pub(crate) fn neon_transpose_8x8_f32<const FLOP: bool, const FLIP: bool>(
         let mut q3 = neon_transpose_4x4_impl_f32::<FLIP>(float32x4x4_t(q3_1, q3_2, q3_3, q3_4)); // D
 
         if FLIP {
-            std::mem::swap(&mut q0, &mut q2);
-            std::mem::swap(&mut q1, &mut q3);
+            core::mem::swap(&mut q0, &mut q2);
+            core::mem::swap(&mut q1, &mut q3);
         }
 
         // Perform an 8 x 8 matrix transpose by building on top of the existing 4 x 4
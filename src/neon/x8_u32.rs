@@ -28,7 +28,7 @@
  */
 use crate::neon::utils::{xvld1q_u8_u32, xvst1q_u8_u32};
 use crate::neon::x4_u32::neon_transpose_4x4_impl;
-use std::arch::aarch64::uint32x4x4_t;
+use core::arch::aarch64::uint32x4x4_t;
 
 #[inline]
 pub(crate) fn neon_transpose_4x4_u8x4x8<const FLOP: bool, const FLIP: bool>(
@@ -64,8 +64,8 @@ pub(crate) fn neon_transpose_4x4_u8x4x8<const FLOP: bool, const FLIP: bool>(
         let mut q3 = neon_transpose_4x4_impl::<FLIP>(uint32x4x4_t(q3_1, q3_2, q3_3, q3_4)); // D
 
         if FLIP {
-            std::mem::swap(&mut q0, &mut q2);
-            std::mem::swap(&mut q1, &mut q3);
+            core::mem::swap(&mut q0, &mut q2);
+            core::mem::swap(&mut q1, &mut q3);
         }
 
         // Perform an 8 x 8 matrix transpose by building on top of the existing 4 x 4
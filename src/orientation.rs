@@ -0,0 +1,187 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::{
+    flip_plane, flip_plane16, flip_plane16_with_alpha, flip_plane_with_alpha, flop_plane,
+    flop_plane16, flop_plane16_with_alpha, flop_plane_with_alpha, rotate180_plane,
+    rotate180_plane16, rotate180_plane16_with_alpha, rotate180_plane_with_alpha, transpose_plane,
+    transpose_plane16, transpose_plane16_with_alpha, transpose_plane_with_alpha, FlipMode,
+    FlopMode, Orientation, TransposeError,
+};
+
+/// Checks that strided buffer holds exactly `height` rows of `width` elements
+pub(crate) fn check_plane(
+    length: usize,
+    stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    if length != stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if stride < width {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    Ok(())
+}
+
+/// Copies `height` rows of `width` elements between strided buffers
+pub(crate) fn copy_plane<V: Copy>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    check_plane(input.len(), input_stride, width, height)?;
+    check_plane(output.len(), output_stride, width, height)?;
+    if width == 0 {
+        return Ok(());
+    }
+    for (dst, src) in output
+        .chunks_exact_mut(output_stride)
+        .zip(input.chunks_exact(input_stride))
+    {
+        dst[..width].copy_from_slice(&src[..width]);
+    }
+    Ok(())
+}
+
+macro_rules! define_orient {
+    ($name:ident, $v:ty, $cn:expr, $transpose:ident, $flip:ident, $flop:ident, $rotate180:ident) => {
+        /// Orients image, output dimensions are swapped if [Orientation::swaps_dimensions] is `true`
+        pub(crate) fn $name(
+            input: &[$v],
+            input_stride: usize,
+            output: &mut [$v],
+            output_stride: usize,
+            width: usize,
+            height: usize,
+            orientation: Orientation,
+        ) -> Result<(), TransposeError> {
+            match orientation {
+                Orientation::Identity => copy_plane(
+                    input,
+                    input_stride,
+                    output,
+                    output_stride,
+                    width * $cn,
+                    height,
+                ),
+                Orientation::Rotate90 => $transpose(
+                    input,
+                    input_stride,
+                    output,
+                    output_stride,
+                    width,
+                    height,
+                    FlipMode::Flip,
+                    FlopMode::Flop,
+                ),
+                Orientation::Rotate180 => {
+                    $rotate180(input, input_stride, output, output_stride, width, height)
+                }
+                Orientation::Rotate270 => $transpose(
+                    input,
+                    input_stride,
+                    output,
+                    output_stride,
+                    width,
+                    height,
+                    FlipMode::NoFlip,
+                    FlopMode::NoFlop,
+                ),
+                Orientation::Flip => {
+                    $flip(input, input_stride, output, output_stride, width, height)
+                }
+                Orientation::Flop => {
+                    $flop(input, input_stride, output, output_stride, width, height)
+                }
+                Orientation::Transpose => $transpose(
+                    input,
+                    input_stride,
+                    output,
+                    output_stride,
+                    width,
+                    height,
+                    FlipMode::NoFlip,
+                    FlopMode::Flop,
+                ),
+                Orientation::Transverse => $transpose(
+                    input,
+                    input_stride,
+                    output,
+                    output_stride,
+                    width,
+                    height,
+                    FlipMode::Flip,
+                    FlopMode::NoFlop,
+                ),
+            }
+        }
+    };
+}
+
+define_orient!(
+    orient_plane8,
+    u8,
+    1,
+    transpose_plane,
+    flip_plane,
+    flop_plane,
+    rotate180_plane
+);
+define_orient!(
+    orient_cbcr8,
+    u8,
+    2,
+    transpose_plane_with_alpha,
+    flip_plane_with_alpha,
+    flop_plane_with_alpha,
+    rotate180_plane_with_alpha
+);
+define_orient!(
+    orient_plane16,
+    u16,
+    1,
+    transpose_plane16,
+    flip_plane16,
+    flop_plane16,
+    rotate180_plane16
+);
+define_orient!(
+    orient_cbcr16,
+    u16,
+    2,
+    transpose_plane16_with_alpha,
+    flip_plane16_with_alpha,
+    flop_plane16_with_alpha,
+    rotate180_plane16_with_alpha
+);
@@ -0,0 +1,94 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::tile::{TransposeFn, TransposeTile};
+use crate::{FlipMode, FlopMode, TransposeError};
+use rayon::prelude::*;
+
+/// Images having less pixels than this are transposed on the calling thread.
+const PARALLEL_THRESHOLD: usize = 512 * 512;
+
+/// Transposes image in bands of output rows using rayon thread pool.
+///
+/// Each band is a [TransposeTile], so bands write disjoint parts
+/// of the output and run independently.
+pub(crate) fn transpose_par<V: Copy + Send + Sync, const CN: usize>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+    executor: TransposeFn<V>,
+) -> Result<(), TransposeError> {
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if output.len() != output_stride * width {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if input_stride < width * CN {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if output_stride < height * CN {
+        return Err(TransposeError::MismatchDimensions);
+    }
+
+    let threads = rayon::current_num_threads();
+    if threads == 1 || width * height < PARALLEL_THRESHOLD {
+        return executor(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            flip_mode,
+            flop_mode,
+        );
+    }
+
+    // A few bands per thread to balance the load, but not thinner than several kernel blocks.
+    let band_rows = width.div_ceil(threads * 4).max(32);
+
+    output
+        .par_chunks_mut(output_stride * band_rows)
+        .enumerate()
+        .try_for_each(|(band, dst)| {
+            TransposeTile::new(dst, output_stride, band * band_rows, width, height).execute::<CN>(
+                input,
+                input_stride,
+                flip_mode,
+                flop_mode,
+                executor,
+            )
+        })
+}
@@ -0,0 +1,298 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::cbcr8::transpose_cbcr8_chunked;
+use crate::rgba16::transpose_rgba16_chunked;
+use crate::rgba8::transpose_rgba8_chunked;
+use crate::transpose_arbitrary::transpose_pod_view;
+use crate::transpose_arbitrary_group::transpose_arbitrary_grouped_view;
+use crate::utils::cast_pod_slices;
+use crate::{FlipMode, FlopMode, TransposeError};
+use alloc::vec::Vec;
+use bytemuck::Pod;
+
+/// Destination axis with its size and stride in the source.
+#[derive(Copy, Clone, Debug)]
+struct Axis {
+    size: usize,
+    stride: usize,
+}
+
+/// Drops unit axes and merges neighbouring destination axes which are also
+/// neighbours in the source, e.g. `H` and `W` of HWC to CHW permutation.
+fn simplify_axes(shape: &[usize], strides: &[usize], perm: &[usize]) -> Vec<Axis> {
+    let mut axes: Vec<Axis> = Vec::with_capacity(perm.len());
+    for &p in perm {
+        let axis = Axis {
+            size: shape[p],
+            stride: strides[p],
+        };
+        if axis.size == 1 {
+            continue;
+        }
+        match axes.last_mut() {
+            Some(outer) if axis.size.checked_mul(axis.stride) == Some(outer.stride) => {
+                outer.size *= axis.size;
+                outer.stride = axis.stride;
+            }
+            _ => axes.push(axis),
+        }
+    }
+    axes
+}
+
+/// Copies destination elements one by one, inner contiguous runs are copied at once.
+fn gather<T: Copy>(src: &[T], offset: usize, axes: &[Axis], dst: &mut [T]) {
+    match axes {
+        [] => dst[0] = src[offset],
+        [inner] if inner.stride == 1 => dst.copy_from_slice(&src[offset..offset + inner.size]),
+        [inner] => {
+            for (i, dst) in dst.iter_mut().enumerate() {
+                *dst = src[offset + i * inner.stride];
+            }
+        }
+        [outer, rest @ ..] => {
+            let chunk = dst.len() / outer.size;
+            for (i, dst) in dst.chunks_exact_mut(chunk).enumerate() {
+                gather(src, offset + i * outer.stride, rest, dst);
+            }
+        }
+    }
+}
+
+/// Runs `f` on every block of the destination indexed by `axes`.
+fn for_each_block<T, F: FnMut(usize, &mut [T]) -> Result<(), TransposeError>>(
+    offset: usize,
+    axes: &[Axis],
+    dst: &mut [T],
+    f: &mut F,
+) -> Result<(), TransposeError> {
+    match axes.split_first() {
+        None => f(offset, dst),
+        Some((outer, rest)) => {
+            let chunk = dst.len() / outer.size;
+            for (i, dst) in dst.chunks_exact_mut(chunk).enumerate() {
+                for_each_block(offset + i * outer.stride, rest, dst, f)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Transposes image of pixels having `group` elements into contiguous output one by one.
+fn gather_transposed<T: Copy>(
+    input: &[T],
+    input_stride: usize,
+    output: &mut [T],
+    width: usize,
+    height: usize,
+    group: usize,
+) -> Result<(), TransposeError> {
+    let axes = [
+        Axis {
+            size: width,
+            stride: group,
+        },
+        Axis {
+            size: height,
+            stride: input_stride,
+        },
+        Axis {
+            size: group,
+            stride: 1,
+        },
+    ];
+    gather(input, 0, &axes, output);
+    Ok(())
+}
+
+/// Transposes image of pixels having `group` elements into contiguous output.
+///
+/// Pixel sizes matching an existing kernel are reinterpreted as bytes, others are gathered.
+fn transpose_groups<T: Pod>(
+    input: &[T],
+    input_stride: usize,
+    output: &mut [T],
+    width: usize,
+    height: usize,
+    group: usize,
+) -> Result<(), TransposeError> {
+    let output_stride = height * group;
+    if group == 1 {
+        return transpose_pod_view(
+            input,
+            input_stride,
+            output,
+            output_stride,
+            width,
+            height,
+            FlipMode::NoFlip,
+            FlopMode::Flop,
+        );
+    }
+    let size = size_of::<T>();
+    let pixel_size = group * size;
+    let (stride_bytes, output_stride_bytes) = (input_stride * size, output_stride * size);
+    match pixel_size {
+        2 => transpose_cbcr8_chunked(
+            bytemuck::cast_slice(input),
+            stride_bytes,
+            bytemuck::cast_slice_mut(output),
+            output_stride_bytes,
+            width,
+            height,
+            FlipMode::NoFlip,
+            FlopMode::Flop,
+        ),
+        3 => transpose_arbitrary_grouped_view::<u8, 3>(
+            bytemuck::cast_slice(input),
+            stride_bytes,
+            bytemuck::cast_slice_mut(output),
+            output_stride_bytes,
+            width,
+            height,
+            FlipMode::NoFlip,
+            FlopMode::Flop,
+        ),
+        4 => transpose_rgba8_chunked(
+            bytemuck::cast_slice(input),
+            stride_bytes,
+            bytemuck::cast_slice_mut(output),
+            output_stride_bytes,
+            width,
+            height,
+            FlipMode::NoFlip,
+            FlopMode::Flop,
+        ),
+        8 => match cast_pod_slices::<T, u16>(input, output) {
+            Some((input, output)) if stride_bytes % 2 == 0 => transpose_rgba16_chunked(
+                input,
+                stride_bytes / 2,
+                output,
+                output_stride_bytes / 2,
+                width,
+                height,
+                FlipMode::NoFlip,
+                FlopMode::Flop,
+            ),
+            _ => gather_transposed(input, input_stride, output, width, height, group),
+        },
+        _ => gather_transposed(input, input_stride, output, width, height, group),
+    }
+}
+
+/// Performs N-dimensional axis permutation, e.g. HWC to CHW or NHWC to NCHW
+///
+/// Destination is contiguous and its axis `i` is the source axis `perm[i]`,
+/// so destination shape is `src_shape[perm[i]]`.
+/// Permutation is reduced to batches of 2D transposes where possible.
+///
+/// # Arguments
+///
+/// * `src`: Source data
+/// * `src_shape`: Source size for every axis
+/// * `src_strides`: Source stride for every axis in elements
+/// * `perm`: Source axis for every destination axis
+/// * `dst`: Destination data
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn permute_axes<T: Pod>(
+    src: &[T],
+    src_shape: &[usize],
+    src_strides: &[usize],
+    perm: &[usize],
+    dst: &mut [T],
+) -> Result<(), TransposeError> {
+    let dims = src_shape.len();
+    if src_strides.len() != dims {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if perm.len() != dims {
+        return Err(TransposeError::InvalidPermutation);
+    }
+    let mut seen = alloc::vec![false; dims];
+    for &p in perm {
+        if p >= dims || seen[p] {
+            return Err(TransposeError::InvalidPermutation);
+        }
+        seen[p] = true;
+    }
+
+    let total = src_shape
+        .iter()
+        .try_fold(1usize, |acc, &size| acc.checked_mul(size))
+        .ok_or(TransposeError::MismatchDimensions)?;
+    if dst.len() != total {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if total == 0 {
+        return Ok(());
+    }
+    let last = src_shape
+        .iter()
+        .zip(src_strides.iter())
+        .try_fold(0usize, |acc, (&size, &stride)| {
+            (size - 1)
+                .checked_mul(stride)
+                .and_then(|x| x.checked_add(acc))
+        })
+        .ok_or(TransposeError::MismatchDimensions)?;
+    if last >= src.len() {
+        return Err(TransposeError::MismatchDimensions);
+    }
+
+    let axes = simplify_axes(src_shape, src_strides, perm);
+
+    // Innermost contiguous run is moved as a whole pixel.
+    let (group, body) = match axes.split_last() {
+        Some((inner, body)) if inner.stride == 1 => (inner.size, body),
+        _ => (1, axes.as_slice()),
+    };
+
+    if let [outer @ .., columns, rows] = body {
+        // Destination block `[columns][rows]` is a transposed source image
+        // having `rows` rows of `columns` pixels.
+        if columns.stride == group && rows.stride >= columns.size * group {
+            return for_each_block(0, outer, dst, &mut |offset, dst| {
+                transpose_groups(
+                    &src[offset..],
+                    rows.stride,
+                    dst,
+                    columns.size,
+                    rows.size,
+                    group,
+                )
+            });
+        }
+    }
+
+    gather(src, 0, &axes, dst);
+    Ok(())
+}
@@ -209,7 +209,7 @@ pub(crate) fn transpose_plane16_chunked(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Result<(), TransposeError> {
-    if input.len() != input_stride * height {
+    if !crate::utils::fits_strided(input.len(), input_stride, height, width) {
         return Err(TransposeError::MismatchDimensions);
     }
     if output.len() != output_stride * width {
@@ -237,13 +237,31 @@ pub(crate) fn transpose_plane16_chunked(
         executor(input, input_stride, output, output_stride, width, height);
         Ok(())
     }
+    #[cfg(static_ssse3)]
+    {
+        unsafe {
+            crate::utils::dispatch_flip_flop!(
+                flip_mode,
+                flop_mode,
+                transpose_plane16_impl_ssse3,
+                input,
+                input_stride,
+                output,
+                output_stride,
+                width,
+                height
+            );
+        }
+        Ok(())
+    }
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
         feature = "unsafe",
-        any(feature = "sse", feature = "avx")
+        feature = "sse",
+        not(static_ssse3)
     ))]
     {
-        if std::arch::is_x86_feature_detected!("ssse3") {
+        if crate::utils::has_x86_feature!("ssse3") {
             let executor: unsafe fn(&[u16], usize, &mut [u16], usize, usize, usize) =
                 match flip_mode {
                     FlipMode::NoFlip => match flop_mode {
@@ -260,10 +278,10 @@ pub(crate) fn transpose_plane16_chunked(
             return Ok(());
         }
     }
-    #[cfg(not(all(target_arch = "aarch64", feature = "unsafe", feature = "neon")))]
+    #[cfg(not(static_dispatch_ssse3))]
     {
-        use crate::transpose_arbitrary::transpose_arbitrary;
-        transpose_arbitrary::<u16>(
+        use crate::transpose_arbitrary::transpose_arbitrary_view;
+        transpose_arbitrary_view::<u16>(
             input,
             input_stride,
             output,
@@ -176,7 +176,7 @@ pub(crate) fn transpose_plane8_chunked(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Result<(), TransposeError> {
-    if input.len() != input_stride * height {
+    if !crate::utils::fits_strided(input.len(), input_stride, height, width) {
         return Err(TransposeError::MismatchDimensions);
     }
     if output.len() != output_stride * width {
@@ -204,43 +204,51 @@ pub(crate) fn transpose_plane8_chunked(
         executor(input, input_stride, output, output_stride, width, height);
         Ok(())
     }
-    #[cfg(all(
-        any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "unsafe",
-        any(feature = "sse", feature = "avx")
-    ))]
+    #[cfg(static_ssse3)]
     {
-        if !std::arch::is_x86_feature_detected!("ssse3") {
-            use crate::transpose_arbitrary::transpose_arbitrary;
-            return transpose_arbitrary::<u8>(
+        unsafe {
+            crate::utils::dispatch_flip_flop!(
+                flip_mode,
+                flop_mode,
+                transpose_plane8_impl_ssse3,
                 input,
                 input_stride,
                 output,
                 output_stride,
                 width,
-                height,
-                flip_mode,
-                flop_mode,
+                height
             );
         }
+        Ok(())
+    }
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        feature = "sse",
+        not(static_ssse3)
+    ))]
+    {
+        if crate::utils::has_x86_feature!("ssse3") {
+            let executor: unsafe fn(&[u8], usize, &mut [u8], usize, usize, usize) = match flip_mode
+            {
+                FlipMode::NoFlip => match flop_mode {
+                    FlopMode::NoFlop => transpose_plane8_impl_ssse3::<false, false>,
+                    FlopMode::Flop => transpose_plane8_impl_ssse3::<true, false>,
+                },
+                FlipMode::Flip => match flop_mode {
+                    FlopMode::NoFlop => transpose_plane8_impl_ssse3::<false, true>,
+                    FlopMode::Flop => transpose_plane8_impl_ssse3::<true, true>,
+                },
+            };
 
-        let executor: unsafe fn(&[u8], usize, &mut [u8], usize, usize, usize) = match flip_mode {
-            FlipMode::NoFlip => match flop_mode {
-                FlopMode::NoFlop => transpose_plane8_impl_ssse3::<false, false>,
-                FlopMode::Flop => transpose_plane8_impl_ssse3::<true, false>,
-            },
-            FlipMode::Flip => match flop_mode {
-                FlopMode::NoFlop => transpose_plane8_impl_ssse3::<false, true>,
-                FlopMode::Flop => transpose_plane8_impl_ssse3::<true, true>,
-            },
-        };
-
-        unsafe { executor(input, input_stride, output, output_stride, width, height) }
+            unsafe { executor(input, input_stride, output, output_stride, width, height) }
+            return Ok(());
+        }
     }
-    #[cfg(not(all(target_arch = "aarch64", feature = "unsafe", feature = "neon")))]
+    #[cfg(not(static_dispatch_ssse3))]
     {
-        use crate::transpose_arbitrary::transpose_arbitrary;
-        transpose_arbitrary::<u8>(
+        use crate::transpose_arbitrary::transpose_arbitrary_view;
+        transpose_arbitrary_view::<u8>(
             input,
             input_stride,
             output,
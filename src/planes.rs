@@ -0,0 +1,318 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::float32_invoker::execute_f32_plane;
+use crate::orientation::{check_plane, copy_plane};
+use crate::plane16::transpose_plane16_chunked;
+use crate::plane8::transpose_plane8_chunked;
+use crate::tile::{TransposeFn, TransposeTile};
+use crate::{
+    flip_plane, flip_plane16, flip_plane_f32, flop_plane, flop_plane16, flop_plane_f32,
+    rotate180_plane, rotate180_plane16, rotate180_plane_f32, FlipMode, FlopMode, Orientation,
+    TransposeError,
+};
+
+type MirrorFn<V> = fn(&[V], usize, &mut [V], usize, usize, usize) -> Result<(), TransposeError>;
+
+/// Kernels used to orient planes of one element type
+struct PlanePlan<V> {
+    transpose: TransposeFn<V>,
+    flip: MirrorFn<V>,
+    flop: MirrorFn<V>,
+    rotate180: MirrorFn<V>,
+}
+
+const PLAN_U8: PlanePlan<u8> = PlanePlan {
+    transpose: transpose_plane8_chunked,
+    flip: flip_plane,
+    flop: flop_plane,
+    rotate180: rotate180_plane,
+};
+
+const PLAN_U16: PlanePlan<u16> = PlanePlan {
+    transpose: transpose_plane16_chunked,
+    flip: flip_plane16,
+    flop: flop_plane16,
+    rotate180: rotate180_plane16,
+};
+
+const PLAN_F32: PlanePlan<f32> = PlanePlan {
+    transpose: execute_f32_plane,
+    flip: flip_plane_f32,
+    flop: flop_plane_f32,
+    rotate180: rotate180_plane_f32,
+};
+
+/// Approximate amount of bytes of all planes processed in one band
+const BAND_BYTES: usize = 256 * 1024;
+
+/// Orients output rows `start..start + rows` of one plane
+fn orient_band<V: Copy>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    start: usize,
+    rows: usize,
+    orientation: Orientation,
+    plan: &PlanePlan<V>,
+) -> Result<(), TransposeError> {
+    // Mirrors keep rows count, so output rows come from the same or mirrored input rows.
+    let same_rows = || start * input_stride..(start + rows) * input_stride;
+    let mirrored_rows = || (height - start - rows) * input_stride..(height - start) * input_stride;
+    let (flip_mode, flop_mode) = match orientation {
+        Orientation::Identity => {
+            return copy_plane(
+                &input[same_rows()],
+                input_stride,
+                output,
+                output_stride,
+                width,
+                rows,
+            );
+        }
+        Orientation::Flip => {
+            return (plan.flip)(
+                &input[same_rows()],
+                input_stride,
+                output,
+                output_stride,
+                width,
+                rows,
+            );
+        }
+        Orientation::Flop => {
+            return (plan.flop)(
+                &input[mirrored_rows()],
+                input_stride,
+                output,
+                output_stride,
+                width,
+                rows,
+            );
+        }
+        Orientation::Rotate180 => {
+            return (plan.rotate180)(
+                &input[mirrored_rows()],
+                input_stride,
+                output,
+                output_stride,
+                width,
+                rows,
+            );
+        }
+        Orientation::Rotate90 => (FlipMode::Flip, FlopMode::Flop),
+        Orientation::Rotate270 => (FlipMode::NoFlip, FlopMode::NoFlop),
+        Orientation::Transpose => (FlipMode::NoFlip, FlopMode::Flop),
+        Orientation::Transverse => (FlipMode::Flip, FlopMode::NoFlop),
+    };
+    TransposeTile::new(output, output_stride, start, width, height).execute::<1>(
+        input,
+        input_stride,
+        flip_mode,
+        flop_mode,
+        plan.transpose,
+    )
+}
+
+fn orient_planes_impl<V: Copy>(
+    input: &[&[V]],
+    input_strides: &[usize],
+    output: &mut [&mut [V]],
+    output_strides: &[usize],
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+    plan: &PlanePlan<V>,
+) -> Result<(), TransposeError> {
+    if input.len() != output.len()
+        || input.len() != input_strides.len()
+        || output.len() != output_strides.len()
+    {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    let (dst_width, dst_height) = orientation.oriented_size(width, height);
+    for ((input, &input_stride), (output, &output_stride)) in input
+        .iter()
+        .zip(input_strides.iter())
+        .zip(output.iter().zip(output_strides.iter()))
+    {
+        check_plane(input.len(), input_stride, width, height)?;
+        check_plane(output.len(), output_stride, dst_width, dst_height)?;
+    }
+    if dst_width == 0 || dst_height == 0 || input.is_empty() {
+        return Ok(());
+    }
+
+    // Planes are processed in bands of output rows, so the same region of every plane
+    // is touched while it's still in cache.
+    let band_bytes = 2 * dst_width * size_of::<V>() * input.len();
+    let band_rows = (BAND_BYTES / band_bytes).max(16) & !15;
+
+    let mut start = 0usize;
+    while start < dst_height {
+        let rows = band_rows.min(dst_height - start);
+        for ((input, &input_stride), (output, &output_stride)) in input
+            .iter()
+            .zip(input_strides.iter())
+            .zip(output.iter_mut().zip(output_strides.iter()))
+        {
+            orient_band(
+                input,
+                input_stride,
+                &mut output[start * output_stride..(start + rows) * output_stride],
+                output_stride,
+                width,
+                height,
+                start,
+                rows,
+                orientation,
+                plan,
+            )?;
+        }
+        start += rows;
+    }
+    Ok(())
+}
+
+/// Rotates or mirrors set of planes of the same size, e.g. planar GBR image
+///
+/// Planes are processed interleaved in bands for cache locality.
+/// Output planes have dimensions returned by [Orientation::oriented_size].
+///
+/// # Arguments
+///
+/// * `input`: Input planes
+/// * `input_strides`: Stride of each input plane
+/// * `output`: Output planes
+/// * `output_strides`: Stride of each output plane
+/// * `width`: Planes width
+/// * `height`: Planes height
+/// * `orientation`: see [Orientation]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn orient_planes(
+    input: &[&[u8]],
+    input_strides: &[usize],
+    output: &mut [&mut [u8]],
+    output_strides: &[usize],
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+) -> Result<(), TransposeError> {
+    orient_planes_impl(
+        input,
+        input_strides,
+        output,
+        output_strides,
+        width,
+        height,
+        orientation,
+        &PLAN_U8,
+    )
+}
+
+/// Rotates or mirrors set of planes of the same size, e.g. planar high bit depth GBR image
+///
+/// Planes are processed interleaved in bands for cache locality.
+/// Output planes have dimensions returned by [Orientation::oriented_size].
+///
+/// # Arguments
+///
+/// * `input`: Input planes
+/// * `input_strides`: Stride of each input plane
+/// * `output`: Output planes
+/// * `output_strides`: Stride of each output plane
+/// * `width`: Planes width
+/// * `height`: Planes height
+/// * `orientation`: see [Orientation]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn orient_planes16(
+    input: &[&[u16]],
+    input_strides: &[usize],
+    output: &mut [&mut [u16]],
+    output_strides: &[usize],
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+) -> Result<(), TransposeError> {
+    orient_planes_impl(
+        input,
+        input_strides,
+        output,
+        output_strides,
+        width,
+        height,
+        orientation,
+        &PLAN_U16,
+    )
+}
+
+/// Rotates or mirrors set of planes of the same size, e.g. EXR layers
+///
+/// Planes are processed interleaved in bands for cache locality.
+/// Output planes have dimensions returned by [Orientation::oriented_size].
+///
+/// # Arguments
+///
+/// * `input`: Input planes
+/// * `input_strides`: Stride of each input plane
+/// * `output`: Output planes
+/// * `output_strides`: Stride of each output plane
+/// * `width`: Planes width
+/// * `height`: Planes height
+/// * `orientation`: see [Orientation]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn orient_planes_f32(
+    input: &[&[f32]],
+    input_strides: &[usize],
+    output: &mut [&mut [f32]],
+    output_strides: &[usize],
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+) -> Result<(), TransposeError> {
+    orient_planes_impl(
+        input,
+        input_strides,
+        output,
+        output_strides,
+        width,
+        height,
+        orientation,
+        &PLAN_F32,
+    )
+}
@@ -76,10 +76,18 @@ impl<const FLOP: bool, const FLIP: bool> TransposeBlock<u16>
     }
 }
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 struct TransposeBlockAvx2_4x4<const FLOP: bool, const FLIP: bool> {}
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 impl<const FLOP: bool, const FLIP: bool> TransposeBlock<u16>
     for TransposeBlockAvx2_4x4<FLOP, FLIP>
 {
@@ -139,7 +147,8 @@ fn transpose_rgba16_impl_neon<const FLOP: bool, const FLIP: bool>(
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
     feature = "unsafe",
-    feature = "sse"
+    feature = "sse",
+    not(static_avx2)
 ))]
 #[target_feature(enable = "ssse3")]
 unsafe fn transpose_rgba16_impl_ssse3<const FLOP: bool, const FLIP: bool>(
@@ -176,7 +185,11 @@ unsafe fn transpose_rgba16_impl_ssse3<const FLOP: bool, const FLIP: bool>(
     )
 }
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 #[target_feature(enable = "avx2")]
 unsafe fn transpose_rgba16_impl_avx2<const FLOP: bool, const FLIP: bool>(
     input: &[u16],
@@ -233,7 +246,7 @@ pub(crate) fn transpose_rgba16_chunked(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Result<(), TransposeError> {
-    if input.len() != input_stride * height {
+    if !crate::utils::fits_strided(input.len(), input_stride, height, width * 4) {
         return Err(TransposeError::MismatchDimensions);
     }
     if output.len() != output_stride * width {
@@ -261,14 +274,49 @@ pub(crate) fn transpose_rgba16_chunked(
         executor(input, input_stride, output, output_stride, width, height);
         Ok(())
     }
+    #[cfg(static_avx2)]
+    {
+        unsafe {
+            crate::utils::dispatch_flip_flop!(
+                flip_mode,
+                flop_mode,
+                transpose_rgba16_impl_avx2,
+                input,
+                input_stride,
+                output,
+                output_stride,
+                width,
+                height
+            );
+        }
+        Ok(())
+    }
+    #[cfg(all(static_ssse3, not(feature = "avx")))]
+    {
+        unsafe {
+            crate::utils::dispatch_flip_flop!(
+                flip_mode,
+                flop_mode,
+                transpose_rgba16_impl_ssse3,
+                input,
+                input_stride,
+                output,
+                output_stride,
+                width,
+                height
+            );
+        }
+        Ok(())
+    }
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
         feature = "unsafe",
-        any(feature = "sse", feature = "avx")
+        feature = "sse",
+        not(any(static_avx2, all(static_ssse3, not(feature = "avx"))))
     ))]
     {
-        #[cfg(all(target_arch = "x86_64", feature = "avx"))]
-        if std::arch::is_x86_feature_detected!("avx2") {
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+        if crate::utils::has_x86_feature!("avx2") {
             let executor = match flip_mode {
                 FlipMode::NoFlip => match flop_mode {
                     FlopMode::NoFlop => transpose_rgba16_impl_avx2::<false, false>,
@@ -284,7 +332,7 @@ pub(crate) fn transpose_rgba16_chunked(
             return Ok(());
         }
 
-        if std::arch::is_x86_feature_detected!("ssse3") {
+        if crate::utils::has_x86_feature!("ssse3") {
             let executor: unsafe fn(&[u16], usize, &mut [u16], usize, usize, usize) =
                 match flip_mode {
                     FlipMode::NoFlip => match flop_mode {
@@ -300,10 +348,10 @@ pub(crate) fn transpose_rgba16_chunked(
             return Ok(());
         }
     }
-    #[cfg(not(all(target_arch = "aarch64", feature = "unsafe", feature = "neon")))]
+    #[cfg(not(static_dispatch))]
     {
-        use crate::transpose_arbitrary_group::transpose_arbitrary_grouped;
-        transpose_arbitrary_grouped::<u16, 4>(
+        use crate::transpose_arbitrary_group::transpose_arbitrary_grouped_view;
+        transpose_arbitrary_grouped_view::<u16, 4>(
             input,
             input_stride,
             output,
@@ -115,8 +115,10 @@ pub(crate) fn transpose_executor<
 ) -> usize {
     let mut y = start_y;
 
-    let mut src_buffer = vec![V::default(); BLOCK_SIZE * BLOCK_SIZE * CN];
-    let mut dst_buffer = vec![V::default(); BLOCK_SIZE * BLOCK_SIZE * CN];
+    let mut src_scratch = [[[V::default(); CN]; BLOCK_SIZE]; BLOCK_SIZE];
+    let mut dst_scratch = [[[V::default(); CN]; BLOCK_SIZE]; BLOCK_SIZE];
+    let src_buffer = src_scratch.as_flattened_mut().as_flattened_mut();
+    let dst_buffer = dst_scratch.as_flattened_mut().as_flattened_mut();
 
     unsafe {
         while y + BLOCK_SIZE < height {
@@ -154,12 +156,7 @@ pub(crate) fn transpose_executor<
                     dst_row.copy_from_slice(src_row);
                 }
 
-                exec.transpose_block(
-                    src_buffer.as_slice(),
-                    BLOCK_SIZE * CN,
-                    dst_buffer.as_mut_slice(),
-                    BLOCK_SIZE * CN,
-                );
+                exec.transpose_block(src_buffer, BLOCK_SIZE * CN, dst_buffer, BLOCK_SIZE * CN);
 
                 let dst = output.get_unchecked_mut(y * CN + output_stride * output_x..);
 
@@ -167,7 +164,7 @@ pub(crate) fn transpose_executor<
                     let src_idx = if FLOP {
                         j * (BLOCK_SIZE * CN)
                     } else {
-                        (BLOCK_SIZE - j - 1) * (BLOCK_SIZE * CN)
+                        (BLOCK_SIZE - rem_x + j) * (BLOCK_SIZE * CN)
                     };
                     let src_row = &dst_buffer.get_unchecked(src_idx..)[..BLOCK_SIZE * CN];
                     let dst_row =
@@ -223,10 +220,18 @@ impl<const FLOP: bool, const FLIP: bool> TransposeBlock<u8> for TransposeBlockSS
     }
 }
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 struct TransposeBlockAvx2_8x8<const FLOP: bool, const FLIP: bool> {}
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx"
+))]
 impl<const FLOP: bool, const FLIP: bool> TransposeBlock<u8> for TransposeBlockAvx2_8x8<FLOP, FLIP> {
     #[inline(always)]
     fn transpose_block(&self, src: &[u8], src_stride: usize, dst: &mut [u8], dst_stride: usize) {
@@ -298,7 +303,8 @@ fn transpose_rgba8_impl_neon<const FLOP: bool, const FLIP: bool>(
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
     feature = "unsafe",
-    feature = "sse"
+    feature = "sse",
+    not(all(static_dispatch_avx512bw, feature = "avx"))
 ))]
 #[target_feature(enable = "ssse3")]
 unsafe fn transpose_rgba8_impl_ssse3<const FLOP: bool, const FLIP: bool>(
@@ -346,7 +352,12 @@ unsafe fn transpose_rgba8_impl_ssse3<const FLOP: bool, const FLIP: bool>(
     )
 }
 
-#[cfg(all(target_arch = "x86_64", feature = "unsafe", feature = "avx"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    feature = "avx",
+    not(static_avx512bw)
+))]
 #[target_feature(enable = "avx2")]
 unsafe fn transpose_rgba8_impl_avx2<const FLOP: bool, const FLIP: bool>(
     input: &[u8],
@@ -461,7 +472,7 @@ pub(crate) fn transpose_rgba8_chunked(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Result<(), TransposeError> {
-    if input.len() != input_stride * height {
+    if !crate::utils::fits_strided(input.len(), input_stride, height, width * 4) {
         return Err(TransposeError::MismatchDimensions);
     }
     if output.len() != output_stride * width {
@@ -489,14 +500,70 @@ pub(crate) fn transpose_rgba8_chunked(
         executor(input, input_stride, output, output_stride, width, height);
         Ok(())
     }
+    #[cfg(static_avx512bw)]
+    {
+        unsafe {
+            crate::utils::dispatch_flip_flop!(
+                flip_mode,
+                flop_mode,
+                transpose_rgba8_impl_avx512,
+                input,
+                input_stride,
+                output,
+                output_stride,
+                width,
+                height
+            );
+        }
+        Ok(())
+    }
+    #[cfg(all(static_avx2, not(feature = "nightly_avx512")))]
+    {
+        unsafe {
+            crate::utils::dispatch_flip_flop!(
+                flip_mode,
+                flop_mode,
+                transpose_rgba8_impl_avx2,
+                input,
+                input_stride,
+                output,
+                output_stride,
+                width,
+                height
+            );
+        }
+        Ok(())
+    }
+    #[cfg(all(static_ssse3, not(feature = "avx")))]
+    {
+        unsafe {
+            crate::utils::dispatch_flip_flop!(
+                flip_mode,
+                flop_mode,
+                transpose_rgba8_impl_ssse3,
+                input,
+                input_stride,
+                output,
+                output_stride,
+                width,
+                height
+            );
+        }
+        Ok(())
+    }
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
         feature = "unsafe",
-        any(feature = "sse", feature = "avx")
+        feature = "sse",
+        not(any(
+            static_avx512bw,
+            all(static_avx2, not(feature = "nightly_avx512")),
+            all(static_ssse3, not(feature = "avx"))
+        ))
     ))]
     {
         #[cfg(all(feature = "nightly_avx512", target_arch = "x86_64"))]
-        if std::arch::is_x86_feature_detected!("avx512bw") {
+        if crate::utils::has_x86_feature!("avx512bw") {
             let executor = match flip_mode {
                 FlipMode::NoFlip => match flop_mode {
                     FlopMode::NoFlop => transpose_rgba8_impl_avx512::<false, false>,
@@ -512,8 +579,8 @@ pub(crate) fn transpose_rgba8_chunked(
             return Ok(());
         }
 
-        #[cfg(all(target_arch = "x86_64", feature = "avx"))]
-        if std::arch::is_x86_feature_detected!("avx2") {
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+        if crate::utils::has_x86_feature!("avx2") {
             let executor = match flip_mode {
                 FlipMode::NoFlip => match flop_mode {
                     FlopMode::NoFlop => transpose_rgba8_impl_avx2::<false, false>,
@@ -529,7 +596,7 @@ pub(crate) fn transpose_rgba8_chunked(
             return Ok(());
         }
 
-        if std::arch::is_x86_feature_detected!("ssse3") {
+        if crate::utils::has_x86_feature!("ssse3") {
             let executor: unsafe fn(&[u8], usize, &mut [u8], usize, usize, usize) = match flip_mode
             {
                 FlipMode::NoFlip => match flop_mode {
@@ -545,10 +612,10 @@ pub(crate) fn transpose_rgba8_chunked(
             return Ok(());
         }
     }
-    #[cfg(not(all(target_arch = "aarch64", feature = "unsafe", feature = "neon")))]
+    #[cfg(not(static_dispatch_avx512bw))]
     {
-        use crate::transpose_arbitrary_group::transpose_arbitrary_grouped;
-        transpose_arbitrary_grouped::<u8, 4>(
+        use crate::transpose_arbitrary_group::transpose_arbitrary_grouped_view;
+        transpose_arbitrary_grouped_view::<u8, 4>(
             input,
             input_stride,
             output,
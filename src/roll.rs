@@ -0,0 +1,361 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::tile::TransposeTile;
+use crate::transpose_arbitrary::transpose_pod_view;
+use crate::{FlipMode, FlopMode, TransposeError};
+use alloc::vec;
+use bytemuck::Pod;
+
+/// Transposed rows are shifted through a scratch band of this size, while it is in cache.
+const BAND_BYTES: usize = 64 * 1024;
+
+/// Copies `src` into `dst` rotated right by `shift`, that is `dst[(i + shift) % len] = src[i]`.
+#[inline(always)]
+fn rotate_row<V: Copy>(src: &[V], dst: &mut [V], shift: usize) {
+    let split = src.len() - shift;
+    dst[shift..].copy_from_slice(&src[..split]);
+    dst[..shift].copy_from_slice(&src[split..]);
+}
+
+/// Circularly shifts an image right by `shift_x` pixels and down by `shift_y` rows.
+pub(crate) fn roll_image<V: Copy, const CN: usize>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    shift_x: usize,
+    shift_y: usize,
+) -> Result<(), TransposeError> {
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if output.len() != output_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if input_stride < width * CN {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if output_stride < width * CN {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if width == 0 || height == 0 {
+        return Ok(());
+    }
+    let shift_x = shift_x % width;
+    let shift_y = shift_y % height;
+
+    // Rows at the bottom of the input wrap around to the top of the output.
+    let (wrapped_dst, dst) = output.split_at_mut(shift_y * output_stride);
+    let (src, wrapped_src) = input.split_at((height - shift_y) * input_stride);
+
+    for (dst, src) in dst
+        .chunks_exact_mut(output_stride)
+        .zip(src.chunks_exact(input_stride))
+        .chain(
+            wrapped_dst
+                .chunks_exact_mut(output_stride)
+                .zip(wrapped_src.chunks_exact(input_stride)),
+        )
+    {
+        rotate_row(&src[..width * CN], &mut dst[..width * CN], shift_x * CN);
+    }
+
+    Ok(())
+}
+
+/// Transposes and circularly shifts transposed image right by `shift_x` columns
+/// and down by `shift_y` rows.
+///
+/// Bands of transposed rows go through scratch buffer and are shifted into
+/// their place in the output right away, so output is written once.
+fn transpose_roll<V: Pod>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+    shift_x: usize,
+    shift_y: usize,
+) -> Result<(), TransposeError> {
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if output.len() != output_stride * width {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if input_stride < width {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if output_stride < height {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if width == 0 || height == 0 {
+        return Ok(());
+    }
+    let shift_x = shift_x % height;
+    let shift_y = shift_y % width;
+
+    let band_rows = (BAND_BYTES / (height * size_of::<V>()).max(1)).max(16) & !15;
+    let mut scratch = vec![V::zeroed(); band_rows.min(width) * height];
+
+    // Transposed row `y` lands on output row `(y + shift_y) % width`.
+    let (wrapped_dst, dst) = output.split_at_mut(shift_y * output_stride);
+    for (first_row, dst) in [(0, dst), (width - shift_y, wrapped_dst)] {
+        for (band, dst) in dst.chunks_mut(output_stride * band_rows).enumerate() {
+            let rows = dst.len() / output_stride;
+            let scratch = &mut scratch[..rows * height];
+            TransposeTile::new(scratch, height, first_row + band * band_rows, width, height)
+                .execute::<1>(
+                    input,
+                    input_stride,
+                    flip_mode,
+                    flop_mode,
+                    transpose_pod_view::<V>,
+                )?;
+            for (dst, src) in dst
+                .chunks_mut(output_stride)
+                .zip(scratch.chunks_exact(height))
+            {
+                rotate_row(src, &mut dst[..height], shift_x);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Performs 2D fftshift, moving zero frequency from the corner to the center
+///
+/// Quadrants are swapped, for odd dimensions center lands at `(width / 2, height / 2)`.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Array width
+/// * `height`: Array height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn fftshift_2d<V: Copy>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    roll_image::<V, 1>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        width / 2,
+        height / 2,
+    )
+}
+
+/// Performs 2D ifftshift, inverse of [fftshift_2d]
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Array width
+/// * `height`: Array height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn ifftshift_2d<V: Copy>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    roll_image::<V, 1>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        width - width / 2,
+        height - height / 2,
+    )
+}
+
+/// Performs transposition followed by 2D fftshift of transposed array in one pass
+///
+/// Shift is computed from dimensions of the output, which has `width` rows and
+/// `height` columns, so output is rolled by `height / 2` columns and `width / 2` rows.
+/// Result is `fftshift(transpose(x))`, which differs from `transpose(fftshift(x))`
+/// when an odd dimension is mirrored, that is odd `width` with [FlopMode::NoFlop]
+/// or odd `height` with [FlipMode::Flip].
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Array width
+/// * `height`: Array height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_fftshift_2d<V: Pod>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_roll(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        height / 2,
+        width / 2,
+    )
+}
+
+/// Performs transposition followed by 2D ifftshift of transposed array in one pass
+///
+/// Shift is computed from dimensions of the output, which has `width` rows and
+/// `height` columns, so output is rolled by `height - height / 2` columns and
+/// `width - width / 2` rows.
+/// Result is `ifftshift(transpose(x))`, which differs from `transpose(ifftshift(x))`
+/// when an odd dimension is mirrored, that is odd `width` with [FlopMode::NoFlop]
+/// or odd `height` with [FlipMode::Flip].
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Array width
+/// * `height`: Array height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_ifftshift_2d<V: Pod>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_roll(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        height - height / 2,
+        width - width / 2,
+    )
+}
+
+/// Performs circular shift of an image, pixels moved past an edge wrap around
+/// to the opposite edge
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `dx`: Shift to the right in pixels, negative values shift to the left
+/// * `dy`: Shift down in rows, negative values shift up
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn roll_2d<V: Copy, const CN: usize>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    dx: isize,
+    dy: isize,
+) -> Result<(), TransposeError> {
+    let wrap = |shift: isize, size: usize| {
+        if size == 0 {
+            0
+        } else {
+            shift.rem_euclid(size as isize) as usize
+        }
+    };
+    roll_image::<V, CN>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        wrap(dx, width),
+        wrap(dy, height),
+    )
+}
@@ -27,6 +27,8 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 use crate::TransposeError;
+#[allow(unused_imports)]
+use alloc::boxed::Box;
 use bytemuck::{AnyBitPattern, NoUninit, Pod};
 
 trait Rotator<V: Copy> {
@@ -74,11 +76,13 @@ macro_rules! rotate_grouped_copy {
     };
 }
 
+#[cfg(not(static_reverse))]
 #[derive(Debug, Copy, Clone, Default)]
 struct CommonGroupedFlipper<V: Copy + Pod + NoUninit + AnyBitPattern, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
+#[cfg(not(static_reverse))]
 impl<V: Copy + Pod + NoUninit + AnyBitPattern, const N: usize> Rotator<V>
     for CommonGroupedFlipper<V, N>
 where
@@ -97,10 +101,14 @@ where
     }
 }
 
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_reverse)
+))]
 #[derive(Debug, Copy, Clone, Default)]
 struct SSSE3GroupedRotator<V: Copy, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 macro_rules! define_rotator_grouped_x86 {
@@ -182,20 +190,34 @@ macro_rules! define_rotator_grouped_aarch64 {
     };
 }
 
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_reverse)
+))]
 define_rotator_grouped_x86!(SSSE3GroupedRotator, "ssse3");
 
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_reverse)
+))]
 #[derive(Debug, Copy, Clone, Default)]
 struct Sse41GroupedRotator<V: Copy, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_reverse)
+))]
 define_rotator_grouped_x86!(Sse41GroupedRotator, "sse4.1");
 
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
 #[derive(Debug, Copy, Clone, Default)]
 struct Avx2GroupedRotator<V: Copy, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 define_rotator_grouped_x86!(Avx2GroupedRotator, "avx2");
@@ -203,14 +225,14 @@ define_rotator_grouped_x86!(Avx2GroupedRotator, "avx2");
 #[cfg(all(target_arch = "aarch64", feature = "unsafe"))]
 #[derive(Debug, Copy, Clone, Default)]
 struct SveGroupedRotator<V: Copy + Pod + NoUninit + AnyBitPattern, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 define_rotator_grouped_aarch64!(SveGroupedRotator, "sve2");
 
 #[derive(Debug, Copy, Clone, Default)]
 struct RotatorGroupedFactory<V: Copy + Pod + NoUninit + AnyBitPattern, const N: usize> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 impl<V: Copy + 'static + Copy + Pod + NoUninit + AnyBitPattern, const N: usize>
@@ -219,23 +241,37 @@ where
     V: Default,
     [V; N]: Pod,
 {
-    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+    #[cfg(x86_avx2)]
+    fn make_rotator(&self) -> Avx2GroupedRotator<V, N> {
+        Avx2GroupedRotator::<V, N>::default()
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        not(x86_avx2)
+    ))]
     fn make_rotator(&self) -> Box<dyn Rotator<V>> {
-        if std::arch::is_x86_feature_detected!("avx2") {
+        if crate::utils::has_x86_feature!("avx2") {
             return Box::new(Avx2GroupedRotator::<V, N>::default());
         }
-        if std::arch::is_x86_feature_detected!("sse4.1") {
+        if crate::utils::has_x86_feature!("sse4.1") {
             return Box::new(Sse41GroupedRotator::<V, N>::default());
         }
-        if std::arch::is_x86_feature_detected!("ssse3") {
+        if crate::utils::has_x86_feature!("ssse3") {
             return Box::new(SSSE3GroupedRotator::<V, N>::default());
         }
         Box::new(CommonGroupedFlipper::<V, N>::default())
     }
 
-    #[cfg(all(target_arch = "aarch64", feature = "unsafe"))]
+    #[cfg(aarch64_sve2)]
+    fn make_rotator(&self) -> SveGroupedRotator<V, N> {
+        SveGroupedRotator::<V, N>::default()
+    }
+
+    #[cfg(all(target_arch = "aarch64", feature = "unsafe", not(aarch64_sve2)))]
     fn make_rotator(&self) -> Box<dyn Rotator<V>> {
-        if std::arch::is_aarch64_feature_detected!("sve2") {
+        if crate::utils::has_aarch64_feature!("sve2") {
             return Box::new(SveGroupedRotator::<V, N>::default());
         }
         Box::new(CommonGroupedFlipper::<V, N>::default())
@@ -245,8 +281,8 @@ where
         all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"),
         all(target_arch = "aarch64", feature = "unsafe")
     )))]
-    fn make_rotator(&self) -> Box<dyn Rotator<V>> {
-        Box::new(CommonGroupedFlipper::<V, N>::default())
+    fn make_rotator(&self) -> CommonGroupedFlipper<V, N> {
+        CommonGroupedFlipper::<V, N>::default()
     }
 }
 
@@ -316,11 +352,13 @@ macro_rules! define_rotator_x86 {
     };
 }
 
+#[cfg(not(static_reverse))]
 #[derive(Debug, Copy, Clone, Default)]
 struct CommonRotator<V: Copy + Default> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
+#[cfg(not(static_reverse))]
 impl<V: Copy + Default> Rotator<V> for CommonRotator<V> {
     #[inline(always)]
     fn rotate(
@@ -337,13 +375,13 @@ impl<V: Copy + Default> Rotator<V> for CommonRotator<V> {
 
 #[derive(Debug, Copy, Clone, Default)]
 struct RotatorFactory<V: Copy + Default + 'static> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 #[cfg(all(target_arch = "aarch64", feature = "unsafe"))]
 #[derive(Debug, Copy, Clone, Default)]
 struct SveRotator<V: Copy + Default + 'static> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 define_rotator_aarch64!(SveRotator, "sve2");
@@ -351,45 +389,77 @@ define_rotator_aarch64!(SveRotator, "sve2");
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
 #[derive(Debug, Copy, Clone, Default)]
 struct Avx2Rotator<V: Copy> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
 define_rotator_x86!(Avx2Rotator, "avx2");
 
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_reverse)
+))]
 #[derive(Debug, Copy, Clone, Default)]
 struct Sse41Rotator<V: Copy> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_reverse)
+))]
 define_rotator_x86!(Sse41Rotator, "sse4.1");
 
-#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_reverse)
+))]
 #[derive(Debug, Copy, Clone, Default)]
 struct SSSE3Rotator<V: Copy> {
-    _phantom: std::marker::PhantomData<V>,
+    _phantom: core::marker::PhantomData<V>,
 }
 
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "unsafe",
+    not(static_reverse)
+))]
 define_rotator_x86!(SSSE3Rotator, "ssse3");
 
 impl<V: Copy + Default + 'static> RotatorFactory<V> {
-    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"))]
+    #[cfg(x86_avx2)]
+    fn make_rotator(&self) -> Avx2Rotator<V> {
+        Avx2Rotator::<V>::default()
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "unsafe",
+        not(x86_avx2)
+    ))]
     fn make_rotator(&self) -> Box<dyn Rotator<V>> {
-        if std::arch::is_x86_feature_detected!("avx2") {
+        if crate::utils::has_x86_feature!("avx2") {
             return Box::new(Avx2Rotator::<V>::default());
         }
-        if std::arch::is_x86_feature_detected!("sse4.1") {
+        if crate::utils::has_x86_feature!("sse4.1") {
             return Box::new(Sse41Rotator::<V>::default());
         }
-        if std::arch::is_x86_feature_detected!("ssse3") {
+        if crate::utils::has_x86_feature!("ssse3") {
             return Box::new(SSSE3Rotator::<V>::default());
         }
         Box::new(CommonRotator::<V>::default())
     }
 
-    #[cfg(all(target_arch = "aarch64", feature = "unsafe"))]
+    #[cfg(aarch64_sve2)]
+    fn make_rotator(&self) -> SveRotator<V> {
+        SveRotator::<V>::default()
+    }
+
+    #[cfg(all(target_arch = "aarch64", feature = "unsafe", not(aarch64_sve2)))]
     fn make_rotator(&self) -> Box<dyn Rotator<V>> {
-        if std::arch::is_aarch64_feature_detected!("sve2") {
+        if crate::utils::has_aarch64_feature!("sve2") {
             return Box::new(SveRotator::<V>::default());
         }
         Box::new(CommonRotator::<V>::default())
@@ -399,8 +469,8 @@ impl<V: Copy + Default + 'static> RotatorFactory<V> {
         all(any(target_arch = "x86", target_arch = "x86_64"), feature = "unsafe"),
         all(target_arch = "aarch64", feature = "unsafe")
     )))]
-    fn make_rotator(&self) -> Box<dyn Rotator<V>> {
-        Box::new(CommonRotator::<V>::default())
+    fn make_rotator(&self) -> CommonRotator<V> {
+        CommonRotator::<V>::default()
     }
 }
 
@@ -425,9 +495,6 @@ pub fn rotate180_arbitrary<V: Copy + Default + 'static>(
     width: usize,
     height: usize,
 ) -> Result<(), TransposeError> {
-    if input.len() != output.len() {
-        return Err(TransposeError::MismatchDimensions);
-    }
     if input.len() != input_stride * height {
         return Err(TransposeError::MismatchDimensions);
     }
@@ -448,6 +515,45 @@ pub fn rotate180_arbitrary<V: Copy + Default + 'static>(
     Ok(())
 }
 
+/// Performs rotating by 180 of `Pod` types
+///
+/// Types of 1, 2, 4, 8 or 16 bytes are processed as unsigned integers or `u64` pairs of the same size,
+/// others are handled as in [rotate180_arbitrary].
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Array width
+/// * `height`: Array height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate180_pod<V: Pod + Default>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    crate::utils::dispatch_pod_size!(
+        V,
+        rotate180_arbitrary,
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height
+    )
+    .unwrap_or_else(|| {
+        rotate180_arbitrary(input, input_stride, output, output_stride, width, height)
+    })
+}
+
 /// Performs arbitrary rotating by 180
 ///
 /// # Arguments
@@ -779,3 +885,27 @@ pub fn rotate180_rgba_f32(
 ) -> Result<(), TransposeError> {
     rotate180_arbitrary_image::<f32, 4>(input, input_stride, output, output_stride, width, height)
 }
+
+/// Performs plane image rotating by 180
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Array width
+/// * `height`: Array height
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate180_plane_f64(
+    input: &[f64],
+    input_stride: usize,
+    output: &mut [f64],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), TransposeError> {
+    rotate180_arbitrary(input, input_stride, output, output_stride, width, height)
+}
@@ -29,9 +29,9 @@
 
 use crate::sse::u16x4_2x2::sse_transpose_u64_2x2_impl;
 #[cfg(target_arch = "x86")]
-use std::arch::x86::*;
+use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 #[inline]
 pub(crate) fn ssse_transpose_f32x2_2x2<const FLOP: bool, const FLIP: bool>(
@@ -0,0 +1,300 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+/// Builds `pshufb` masks moving bytes between `CN` interleaved registers
+/// and `CN` planar registers of samples of `S` bytes.
+///
+/// `masks[p][i]` selects bytes of interleaved register `i` for planar register `p`
+/// when deinterleaving, and bytes of planar register `i` for interleaved
+/// register `p` otherwise. Bytes not taken from a register are zeroed with `0x80`.
+const fn make_shuffle_masks<const CN: usize, const S: usize>(
+    deinterleave: bool,
+) -> [[[u8; 16]; CN]; CN] {
+    let mut masks = [[[0x80u8; 16]; CN]; CN];
+    let mut p = 0usize;
+    while p < CN {
+        let mut b = 0usize;
+        while b < 16 {
+            if deinterleave {
+                // Sample `b / S` of channel `p` sits at this offset in interleaved registers
+                let offset = ((b / S) * CN + p) * S + b % S;
+                masks[p][offset / 16][b] = (offset % 16) as u8;
+            } else {
+                // Byte `b` of interleaved register `p` belongs to this pixel and channel
+                let offset = p * 16 + b;
+                let channel = (offset / S) % CN;
+                let pixel = offset / (S * CN);
+                masks[p][channel][b] = (pixel * S + offset % S) as u8;
+            }
+            b += 1;
+        }
+        p += 1;
+    }
+    masks
+}
+
+/// Returns true when mask takes no bytes at all, so shuffle may be skipped
+pub(crate) const fn is_empty_mask(mask: &[u8; 16]) -> bool {
+    let mut b = 0usize;
+    while b < 16 {
+        if mask[b] != 0x80 {
+            return false;
+        }
+        b += 1;
+    }
+    true
+}
+
+/// Builds `pshufb` mask which groups samples of one register by channel,
+/// so every channel takes `16 / CN` contiguous bytes, or the inverse of it.
+const fn make_group_mask<const CN: usize, const S: usize>(group: bool) -> [u8; 16] {
+    let mut mask = [0x80u8; 16];
+    if CN * S > 16 || 16 % (CN * S) != 0 {
+        return mask;
+    }
+    let pixels = 16 / (CN * S);
+    let mut b = 0usize;
+    while b < 16 {
+        let channel = b / (pixels * S);
+        let pixel = (b % (pixels * S)) / S;
+        let interleaved = (pixel * CN + channel) * S + b % S;
+        if group {
+            mask[b] = interleaved as u8;
+        } else {
+            mask[interleaved] = b as u8;
+        }
+        b += 1;
+    }
+    mask
+}
+
+pub(crate) struct ShuffleMasks<const CN: usize, const S: usize> {}
+
+impl<const CN: usize, const S: usize> ShuffleMasks<CN, S> {
+    pub(crate) const DEINTERLEAVE: [[[u8; 16]; CN]; CN] = make_shuffle_masks::<CN, S>(true);
+    pub(crate) const INTERLEAVE: [[[u8; 16]; CN]; CN] = make_shuffle_masks::<CN, S>(false);
+    pub(crate) const GROUP: [u8; 16] = make_group_mask::<CN, S>(true);
+    pub(crate) const UNGROUP: [u8; 16] = make_group_mask::<CN, S>(false);
+}
+
+#[inline(always)]
+unsafe fn transpose_4x4_epi32(v: [__m128i; 4]) -> [__m128i; 4] {
+    let t0 = _mm_unpacklo_epi32(v[0], v[1]);
+    let t1 = _mm_unpackhi_epi32(v[0], v[1]);
+    let t2 = _mm_unpacklo_epi32(v[2], v[3]);
+    let t3 = _mm_unpackhi_epi32(v[2], v[3]);
+    [
+        _mm_unpacklo_epi64(t0, t2),
+        _mm_unpackhi_epi64(t0, t2),
+        _mm_unpacklo_epi64(t1, t3),
+        _mm_unpackhi_epi64(t1, t3),
+    ]
+}
+
+/// Converts `CN` interleaved registers into `CN` planar registers
+#[inline(always)]
+unsafe fn deinterleave_registers<const CN: usize, const S: usize>(
+    v: [__m128i; CN],
+) -> [__m128i; CN] {
+    match CN {
+        // Group channels in halves of every register, then take halves of both
+        2 => {
+            let group = _mm_loadu_si128(ShuffleMasks::<CN, S>::GROUP.as_ptr() as *const _);
+            let g0 = _mm_shuffle_epi8(v[0], group);
+            let g1 = _mm_shuffle_epi8(v[1], group);
+            let planes = [_mm_unpacklo_epi64(g0, g1), _mm_unpackhi_epi64(g0, g1)];
+            core::array::from_fn(|i| planes[i])
+        }
+        // Group channels in quarters of every register, then transpose quarters
+        4 => {
+            let group = _mm_loadu_si128(ShuffleMasks::<CN, S>::GROUP.as_ptr() as *const _);
+            let planes = transpose_4x4_epi32([
+                _mm_shuffle_epi8(v[0], group),
+                _mm_shuffle_epi8(v[1], group),
+                _mm_shuffle_epi8(v[2], group),
+                _mm_shuffle_epi8(v[3], group),
+            ]);
+            core::array::from_fn(|i| planes[i])
+        }
+        _ => {
+            let mut out = [_mm_setzero_si128(); CN];
+            for (out, masks) in out
+                .iter_mut()
+                .zip(ShuffleMasks::<CN, S>::DEINTERLEAVE.iter())
+            {
+                *out = shuffle_gather(&v, masks);
+            }
+            out
+        }
+    }
+}
+
+/// Converts `CN` planar registers into `CN` interleaved registers
+#[inline(always)]
+unsafe fn interleave_registers<const CN: usize, const S: usize>(v: [__m128i; CN]) -> [__m128i; CN] {
+    match CN {
+        2 => {
+            let interleaved = match S {
+                1 => [_mm_unpacklo_epi8(v[0], v[1]), _mm_unpackhi_epi8(v[0], v[1])],
+                2 => [
+                    _mm_unpacklo_epi16(v[0], v[1]),
+                    _mm_unpackhi_epi16(v[0], v[1]),
+                ],
+                _ => [
+                    _mm_unpacklo_epi32(v[0], v[1]),
+                    _mm_unpackhi_epi32(v[0], v[1]),
+                ],
+            };
+            core::array::from_fn(|i| interleaved[i])
+        }
+        // Transpose quarters into registers grouped by channel, then ungroup them
+        4 => {
+            let ungroup = _mm_loadu_si128(ShuffleMasks::<CN, S>::UNGROUP.as_ptr() as *const _);
+            let grouped = transpose_4x4_epi32([v[0], v[1], v[2], v[3]]);
+            core::array::from_fn(|i| _mm_shuffle_epi8(grouped[i], ungroup))
+        }
+        _ => {
+            let mut out = [_mm_setzero_si128(); CN];
+            for (out, masks) in out.iter_mut().zip(ShuffleMasks::<CN, S>::INTERLEAVE.iter()) {
+                *out = shuffle_gather(&v, masks);
+            }
+            out
+        }
+    }
+}
+
+/// Gathers register `p` of the other layout from `CN` registers
+#[inline(always)]
+unsafe fn shuffle_gather<const CN: usize>(v: &[__m128i; CN], masks: &[[u8; 16]; CN]) -> __m128i {
+    let mut acc = _mm_setzero_si128();
+    for (&v, mask) in v.iter().zip(masks.iter()) {
+        if !is_empty_mask(mask) {
+            let mask = _mm_loadu_si128(mask.as_ptr() as *const _);
+            acc = _mm_or_si128(acc, _mm_shuffle_epi8(v, mask));
+        }
+    }
+    acc
+}
+
+#[inline(always)]
+pub(crate) unsafe fn deinterleave_bytes<const CN: usize, const S: usize>(
+    src: *const u8,
+    dst: [*mut u8; CN],
+    width: usize,
+) -> usize {
+    let lanes = 16 / S;
+    let mut x = 0usize;
+    while x + lanes <= width {
+        let src = src.add(x * CN * S);
+        let v: [__m128i; CN] =
+            core::array::from_fn(|i| _mm_loadu_si128(src.add(i * 16) as *const _));
+        let planar = deinterleave_registers::<CN, S>(v);
+        for (&v, &dst) in planar.iter().zip(dst.iter()) {
+            _mm_storeu_si128(dst.add(x * S) as *mut _, v);
+        }
+        x += lanes;
+    }
+    x
+}
+
+#[inline(always)]
+pub(crate) unsafe fn interleave_bytes<const CN: usize, const S: usize>(
+    src: [*const u8; CN],
+    dst: *mut u8,
+    width: usize,
+) -> usize {
+    let lanes = 16 / S;
+    let mut x = 0usize;
+    while x + lanes <= width {
+        let v: [__m128i; CN] =
+            core::array::from_fn(|i| _mm_loadu_si128(src[i].add(x * S) as *const _));
+        let dst = dst.add(x * CN * S);
+        let interleaved = interleave_registers::<CN, S>(v);
+        for (i, &v) in interleaved.iter().enumerate() {
+            _mm_storeu_si128(dst.add(i * 16) as *mut _, v);
+        }
+        x += lanes;
+    }
+    x
+}
+
+/// Splits `width` pixels of `CN` interleaved channels into `CN` planes,
+/// `CN` is 2, 3 or 4 and samples are 1, 2 or 4 bytes wide.
+///
+/// Returns amount of pixels processed, rest is left to the caller,
+/// other layouts are not processed at all.
+#[inline]
+#[target_feature(enable = "ssse3")]
+pub(crate) unsafe fn ssse3_deinterleave_row<V: Copy, const CN: usize>(
+    src: &[V],
+    dst: &mut [&mut [V]; CN],
+    width: usize,
+) -> usize {
+    if !(2..=4).contains(&CN) {
+        return 0;
+    }
+    let src = &src[..width * CN];
+    let dst: [*mut u8; CN] = core::array::from_fn(|i| dst[i][..width].as_mut_ptr() as *mut u8);
+    let src = src.as_ptr() as *const u8;
+    match size_of::<V>() {
+        1 => deinterleave_bytes::<CN, 1>(src, dst, width),
+        2 => deinterleave_bytes::<CN, 2>(src, dst, width),
+        4 => deinterleave_bytes::<CN, 4>(src, dst, width),
+        _ => 0,
+    }
+}
+
+/// Merges `CN` planes into `width` pixels of `CN` interleaved channels,
+/// `CN` is 2, 3 or 4 and samples are 1, 2 or 4 bytes wide.
+///
+/// Returns amount of pixels processed, rest is left to the caller,
+/// other layouts are not processed at all.
+#[inline]
+#[target_feature(enable = "ssse3")]
+pub(crate) unsafe fn ssse3_interleave_row<V: Copy, const CN: usize>(
+    src: &[&[V]; CN],
+    dst: &mut [V],
+    width: usize,
+) -> usize {
+    if !(2..=4).contains(&CN) {
+        return 0;
+    }
+    let src: [*const u8; CN] = core::array::from_fn(|i| src[i][..width].as_ptr() as *const u8);
+    let dst = dst[..width * CN].as_mut_ptr() as *mut u8;
+    match size_of::<V>() {
+        1 => interleave_bytes::<CN, 1>(src, dst, width),
+        2 => interleave_bytes::<CN, 2>(src, dst, width),
+        4 => interleave_bytes::<CN, 4>(src, dst, width),
+        _ => 0,
+    }
+}
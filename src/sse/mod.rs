@@ -27,6 +27,9 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 mod f32x2_2x2;
+mod f64x2_2x2;
+mod f64x2_4x4;
+mod interleave;
 mod u16_4x4;
 mod u16_8x8;
 mod u16x4_2x2;
@@ -34,12 +37,18 @@ mod u8_8x8;
 mod u8x2_4x4;
 mod u8x2_8x8;
 mod utils;
+mod x2_f64;
 mod x4_f32;
 mod x4_u32;
 mod x8_f32;
 mod x8_u32;
 
 pub(crate) use f32x2_2x2::ssse_transpose_f32x2_2x2;
+pub(crate) use f64x2_2x2::sse_transpose_f64x2_2x2;
+pub(crate) use f64x2_4x4::sse_transpose_f64x2_4x4;
+#[cfg(feature = "avx")]
+pub(crate) use interleave::{deinterleave_bytes, interleave_bytes, is_empty_mask, ShuffleMasks};
+pub(crate) use interleave::{ssse3_deinterleave_row, ssse3_interleave_row};
 pub(crate) use u16_4x4::sse_transpose_4x4_u16;
 pub(crate) use u16_8x8::sse_transpose_8x8_u16;
 pub(crate) use u16x4_2x2::ssse_transpose_u16x4_2x2;
@@ -47,6 +56,7 @@ pub(crate) use u8_8x8::sse_transpose_u8_8x8;
 pub(crate) use u8x2_4x4::sse_transpose_u8x2_4x4;
 pub(crate) use u8x2_8x8::sse_transpose_u8x2_8x8;
 pub(crate) use utils::_mm_shuffle;
+pub(crate) use x2_f64::sse_transpose_2x2_f64;
 pub(crate) use x4_f32::sse_transpose_4x4_f32;
 pub(crate) use x4_u32::sse_transpose_4x4_u32x1;
 pub(crate) use x8_f32::sse_transpose_8x8_f32;
@@ -28,9 +28,9 @@
  */
 
 #[cfg(target_arch = "x86")]
-use std::arch::x86::*;
+use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 pub(crate) struct M128x4(
     pub(crate) __m128i,
@@ -29,9 +29,9 @@
 
 use crate::sse::_mm_shuffle;
 #[cfg(target_arch = "x86")]
-use std::arch::x86::*;
+use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 #[inline(always)]
 pub(crate) unsafe fn sse_transpose_u64_2x2_impl<const FLIP: bool>(
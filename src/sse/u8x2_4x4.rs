@@ -29,9 +29,9 @@
 
 use crate::sse::u16_4x4::sse_transpose_u16_4x4_impl;
 #[cfg(target_arch = "x86")]
-use std::arch::x86::*;
+use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 #[inline]
 pub(crate) fn sse_transpose_u8x2_4x4<const FLOP: bool, const FLIP: bool>(
@@ -28,9 +28,9 @@
  */
 
 #[cfg(target_arch = "x86")]
-use std::arch::x86::*;
+use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 #[inline(always)]
 pub(crate) unsafe fn sse_transpose_4x4_impl_f32<const FLIP: bool>(
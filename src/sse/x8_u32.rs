@@ -28,9 +28,9 @@
  */
 use crate::sse::x4_u32::sse_transpose_4x4_impl;
 #[cfg(target_arch = "x86")]
-use std::arch::x86::*;
+use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
+use core::arch::x86_64::*;
 
 #[inline(always)]
 pub(crate) fn sse_transpose_8x8_u32x1<const FLOP: bool, const FLIP: bool>(
@@ -72,8 +72,8 @@ pub(crate) fn sse_transpose_8x8_u32x1<const FLOP: bool, const FLIP: bool>(
         let mut q3 = sse_transpose_4x4_impl::<FLIP>((q3_1, q3_2, q3_3, q3_4)); // D
 
         if FLIP {
-            std::mem::swap(&mut q0, &mut q2);
-            std::mem::swap(&mut q1, &mut q3);
+            core::mem::swap(&mut q0, &mut q2);
+            core::mem::swap(&mut q1, &mut q3);
         }
 
         // Perform an 8 x 8 matrix transpose by building on top of the existing 4 x 4
@@ -0,0 +1,734 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::cbcr8::transpose_cbcr8_chunked;
+use crate::float32_cbcr_invoker::execute_f32_cbcr;
+use crate::float32_invoker::execute_f32_plane;
+use crate::plane16::transpose_plane16_chunked;
+use crate::plane8::transpose_plane8_chunked;
+use crate::rgba16::transpose_rgba16_chunked;
+use crate::rgba8::transpose_rgba8_chunked;
+use crate::transpose_arbitrary_group::transpose_arbitrary_grouped_view;
+use crate::{FlipMode, FlopMode, TransposeError};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::Enumerate;
+use core::slice::ChunksMut;
+
+pub(crate) type TransposeFn<V> = fn(
+    &[V],
+    usize,
+    &mut [V],
+    usize,
+    usize,
+    usize,
+    FlipMode,
+    FlopMode,
+) -> Result<(), TransposeError>;
+
+/// Independent part of an image transposition.
+///
+/// Tile covers a range of rows of the transposed image, that is a range of input columns,
+/// and a range of its columns, that is a range of input rows. It exclusively owns
+/// its part of the output, so tiles may be processed on any thread in any order.
+#[derive(Debug)]
+pub struct TransposeTile<'a, V> {
+    output: TileOutput<'a, V>,
+    output_stride: usize,
+    start_row: usize,
+    start_column: usize,
+    columns: usize,
+    width: usize,
+    height: usize,
+}
+
+/// Output owned by a tile
+#[derive(Debug)]
+enum TileOutput<'a, V> {
+    /// Whole output rows
+    Rows(&'a mut [V]),
+    /// Part of each output row, `channels` values per pixel
+    Fragments {
+        rows: Vec<&'a mut [V]>,
+        channels: usize,
+    },
+}
+
+impl<'a, V: Copy> TransposeTile<'a, V> {
+    pub(crate) fn new(
+        output: &'a mut [V],
+        output_stride: usize,
+        start_row: usize,
+        width: usize,
+        height: usize,
+    ) -> Self {
+        TransposeTile {
+            output: TileOutput::Rows(output),
+            output_stride,
+            start_row,
+            start_column: 0,
+            columns: height,
+            width,
+            height,
+        }
+    }
+
+    /// First output row covered by the tile
+    pub fn start_row(&self) -> usize {
+        self.start_row
+    }
+
+    /// Number of output rows covered by the tile
+    pub fn rows(&self) -> usize {
+        match &self.output {
+            TileOutput::Rows(output) => output
+                .len()
+                .checked_div(self.output_stride)
+                .unwrap_or_default(),
+            TileOutput::Fragments { rows, .. } => rows.len(),
+        }
+    }
+
+    /// First output column covered by the tile
+    pub fn start_column(&self) -> usize {
+        self.start_column
+    }
+
+    /// Number of output columns covered by the tile
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Output stride
+    pub fn output_stride(&self) -> usize {
+        self.output_stride
+    }
+
+    /// Part of output row `start_row + row` covered by the tile, e.g. to continue
+    /// with other per-tile work.
+    ///
+    /// Tiles made by [transpose_tiles] cover whole rows including the stride padding.
+    ///
+    /// # Panics
+    ///
+    /// When `row` is not less than [TransposeTile::rows].
+    pub fn output_row(&mut self, row: usize) -> &mut [V] {
+        match &mut self.output {
+            TileOutput::Rows(output) => {
+                &mut output[row * self.output_stride..(row + 1) * self.output_stride]
+            }
+            TileOutput::Fragments { rows, .. } => rows[row],
+        }
+    }
+
+    pub(crate) fn execute<const CN: usize>(
+        &mut self,
+        input: &[V],
+        input_stride: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+        executor: TransposeFn<V>,
+    ) -> Result<(), TransposeError> {
+        if input.len() != input_stride * self.height {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if input_stride < self.width * CN {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if self.output_stride < self.height * CN {
+            return Err(TransposeError::MismatchDimensions);
+        }
+        if let TileOutput::Fragments { channels, .. } = self.output {
+            if channels != CN {
+                return Err(TransposeError::MismatchDimensions);
+            }
+        }
+        let rows = self.rows();
+        let columns = self.columns;
+        if rows == 0 || columns == 0 {
+            return Ok(());
+        }
+        // Output row `y` holds input column `y` when flopped, `width - 1 - y` otherwise.
+        let start_x = match flop_mode {
+            FlopMode::Flop => self.start_row,
+            FlopMode::NoFlop => self.width - self.start_row - rows,
+        };
+        // Output column `x` holds input row `height - 1 - x` when flipped, `x` otherwise.
+        let start_y = match flip_mode {
+            FlipMode::NoFlip => self.start_column,
+            FlipMode::Flip => self.height - self.start_column - columns,
+        };
+        let input = &input[start_y * input_stride + start_x * CN..];
+        match &mut self.output {
+            TileOutput::Rows(output) => executor(
+                input,
+                input_stride,
+                output,
+                self.output_stride,
+                rows,
+                columns,
+                flip_mode,
+                flop_mode,
+            ),
+            TileOutput::Fragments {
+                rows: fragments, ..
+            } => {
+                // Kernels need contiguous output, so the tile is transposed
+                // into a small scratch and then copied out.
+                let scratch_stride = columns * CN;
+                let mut scratch = vec![fragments[0][0]; scratch_stride * rows];
+                executor(
+                    input,
+                    input_stride,
+                    &mut scratch,
+                    scratch_stride,
+                    rows,
+                    columns,
+                    flip_mode,
+                    flop_mode,
+                )?;
+                for (dst, src) in fragments
+                    .iter_mut()
+                    .zip(scratch.chunks_exact(scratch_stride))
+                {
+                    dst.copy_from_slice(src);
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl TransposeTile<'_, u8> {
+    /// Performs plane image transposition of the tile
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: Input data of the whole image
+    /// * `input_stride`: Input data stride
+    /// * `flip_mode`: see [FlipMode]
+    /// * `flop_mode`: see [FlopMode], must be the same for all tiles of the image
+    ///
+    /// returns: Result<(), TransposeError>
+    ///
+    pub fn transpose_plane(
+        &mut self,
+        input: &[u8],
+        input_stride: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+    ) -> Result<(), TransposeError> {
+        self.execute::<1>(
+            input,
+            input_stride,
+            flip_mode,
+            flop_mode,
+            transpose_plane8_chunked,
+        )
+    }
+
+    /// Performs plane with alpha image transposition of the tile
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: Input data of the whole image
+    /// * `input_stride`: Input data stride
+    /// * `flip_mode`: see [FlipMode]
+    /// * `flop_mode`: see [FlopMode], must be the same for all tiles of the image
+    ///
+    /// returns: Result<(), TransposeError>
+    ///
+    pub fn transpose_plane_with_alpha(
+        &mut self,
+        input: &[u8],
+        input_stride: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+    ) -> Result<(), TransposeError> {
+        self.execute::<2>(
+            input,
+            input_stride,
+            flip_mode,
+            flop_mode,
+            transpose_cbcr8_chunked,
+        )
+    }
+
+    /// Performs RGB image transposition of the tile
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: Input data of the whole image
+    /// * `input_stride`: Input data stride
+    /// * `flip_mode`: see [FlipMode]
+    /// * `flop_mode`: see [FlopMode], must be the same for all tiles of the image
+    ///
+    /// returns: Result<(), TransposeError>
+    ///
+    pub fn transpose_rgb(
+        &mut self,
+        input: &[u8],
+        input_stride: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+    ) -> Result<(), TransposeError> {
+        self.execute::<3>(
+            input,
+            input_stride,
+            flip_mode,
+            flop_mode,
+            transpose_arbitrary_grouped_view::<u8, 3>,
+        )
+    }
+
+    /// Performs RGBA image transposition of the tile
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: Input data of the whole image
+    /// * `input_stride`: Input data stride
+    /// * `flip_mode`: see [FlipMode]
+    /// * `flop_mode`: see [FlopMode], must be the same for all tiles of the image
+    ///
+    /// returns: Result<(), TransposeError>
+    ///
+    pub fn transpose_rgba(
+        &mut self,
+        input: &[u8],
+        input_stride: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+    ) -> Result<(), TransposeError> {
+        self.execute::<4>(
+            input,
+            input_stride,
+            flip_mode,
+            flop_mode,
+            transpose_rgba8_chunked,
+        )
+    }
+}
+
+impl TransposeTile<'_, u16> {
+    /// Performs plane image transposition of the tile
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: Input data of the whole image
+    /// * `input_stride`: Input data stride
+    /// * `flip_mode`: see [FlipMode]
+    /// * `flop_mode`: see [FlopMode], must be the same for all tiles of the image
+    ///
+    /// returns: Result<(), TransposeError>
+    ///
+    pub fn transpose_plane16(
+        &mut self,
+        input: &[u16],
+        input_stride: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+    ) -> Result<(), TransposeError> {
+        self.execute::<1>(
+            input,
+            input_stride,
+            flip_mode,
+            flop_mode,
+            transpose_plane16_chunked,
+        )
+    }
+
+    /// Performs plane with alpha image transposition of the tile
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: Input data of the whole image
+    /// * `input_stride`: Input data stride
+    /// * `flip_mode`: see [FlipMode]
+    /// * `flop_mode`: see [FlopMode], must be the same for all tiles of the image
+    ///
+    /// returns: Result<(), TransposeError>
+    ///
+    pub fn transpose_plane16_with_alpha(
+        &mut self,
+        input: &[u16],
+        input_stride: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+    ) -> Result<(), TransposeError> {
+        self.execute::<2>(
+            input,
+            input_stride,
+            flip_mode,
+            flop_mode,
+            transpose_arbitrary_grouped_view::<u16, 2>,
+        )
+    }
+
+    /// Performs RGB image transposition of the tile
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: Input data of the whole image
+    /// * `input_stride`: Input data stride
+    /// * `flip_mode`: see [FlipMode]
+    /// * `flop_mode`: see [FlopMode], must be the same for all tiles of the image
+    ///
+    /// returns: Result<(), TransposeError>
+    ///
+    pub fn transpose_rgb16(
+        &mut self,
+        input: &[u16],
+        input_stride: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+    ) -> Result<(), TransposeError> {
+        self.execute::<3>(
+            input,
+            input_stride,
+            flip_mode,
+            flop_mode,
+            transpose_arbitrary_grouped_view::<u16, 3>,
+        )
+    }
+
+    /// Performs RGBA image transposition of the tile
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: Input data of the whole image
+    /// * `input_stride`: Input data stride
+    /// * `flip_mode`: see [FlipMode]
+    /// * `flop_mode`: see [FlopMode], must be the same for all tiles of the image
+    ///
+    /// returns: Result<(), TransposeError>
+    ///
+    pub fn transpose_rgba16(
+        &mut self,
+        input: &[u16],
+        input_stride: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+    ) -> Result<(), TransposeError> {
+        self.execute::<4>(
+            input,
+            input_stride,
+            flip_mode,
+            flop_mode,
+            transpose_rgba16_chunked,
+        )
+    }
+}
+
+impl TransposeTile<'_, f32> {
+    /// Performs plane image transposition of the tile
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: Input data of the whole image
+    /// * `input_stride`: Input data stride
+    /// * `flip_mode`: see [FlipMode]
+    /// * `flop_mode`: see [FlopMode], must be the same for all tiles of the image
+    ///
+    /// returns: Result<(), TransposeError>
+    ///
+    pub fn transpose_plane_f32(
+        &mut self,
+        input: &[f32],
+        input_stride: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+    ) -> Result<(), TransposeError> {
+        self.execute::<1>(input, input_stride, flip_mode, flop_mode, execute_f32_plane)
+    }
+
+    /// Performs plane with alpha image transposition of the tile
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: Input data of the whole image
+    /// * `input_stride`: Input data stride
+    /// * `flip_mode`: see [FlipMode]
+    /// * `flop_mode`: see [FlopMode], must be the same for all tiles of the image
+    ///
+    /// returns: Result<(), TransposeError>
+    ///
+    pub fn transpose_plane_f32_with_alpha(
+        &mut self,
+        input: &[f32],
+        input_stride: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+    ) -> Result<(), TransposeError> {
+        self.execute::<2>(input, input_stride, flip_mode, flop_mode, execute_f32_cbcr)
+    }
+
+    /// Performs RGB image transposition of the tile
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: Input data of the whole image
+    /// * `input_stride`: Input data stride
+    /// * `flip_mode`: see [FlipMode]
+    /// * `flop_mode`: see [FlopMode], must be the same for all tiles of the image
+    ///
+    /// returns: Result<(), TransposeError>
+    ///
+    pub fn transpose_rgb_f32(
+        &mut self,
+        input: &[f32],
+        input_stride: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+    ) -> Result<(), TransposeError> {
+        self.execute::<3>(
+            input,
+            input_stride,
+            flip_mode,
+            flop_mode,
+            transpose_arbitrary_grouped_view::<f32, 3>,
+        )
+    }
+
+    /// Performs RGBA image transposition of the tile
+    ///
+    /// # Arguments
+    ///
+    /// * `input`: Input data of the whole image
+    /// * `input_stride`: Input data stride
+    /// * `flip_mode`: see [FlipMode]
+    /// * `flop_mode`: see [FlopMode], must be the same for all tiles of the image
+    ///
+    /// returns: Result<(), TransposeError>
+    ///
+    pub fn transpose_rgba_f32(
+        &mut self,
+        input: &[f32],
+        input_stride: usize,
+        flip_mode: FlipMode,
+        flop_mode: FlopMode,
+    ) -> Result<(), TransposeError> {
+        self.execute::<4>(
+            input,
+            input_stride,
+            flip_mode,
+            flop_mode,
+            transpose_arbitrary_grouped_view::<f32, 4>,
+        )
+    }
+}
+
+/// Iterator over independent tiles of an image transposition, see [transpose_tiles]
+#[derive(Debug)]
+pub struct TransposeTiles<'a, V> {
+    chunks: Enumerate<ChunksMut<'a, V>>,
+    output_stride: usize,
+    tile_rows: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, V: Copy> Iterator for TransposeTiles<'a, V> {
+    type Item = TransposeTile<'a, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(|(index, output)| {
+            TransposeTile::new(
+                output,
+                self.output_stride,
+                index * self.tile_rows,
+                self.width,
+                self.height,
+            )
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<V: Copy> ExactSizeIterator for TransposeTiles<'_, V> {}
+
+/// Splits transposition output into independent tiles
+///
+/// Each tile covers `tile_rows` whole rows of the transposed image, the last one may be shorter.
+/// Tiles run the same kernels as full image transposition and may be sent
+/// to different threads.
+///
+/// # Arguments
+///
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Input image width
+/// * `height`: Input image height
+/// * `tile_rows`: Number of output rows in a tile, at least 1
+///
+/// returns: Result<TransposeTiles, TransposeError>
+///
+pub fn transpose_tiles<V: Copy>(
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    tile_rows: usize,
+) -> Result<TransposeTiles<'_, V>, TransposeError> {
+    if output.len() != output_stride * width {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    let tile_rows = tile_rows.max(1);
+    Ok(TransposeTiles {
+        chunks: output
+            .chunks_mut((output_stride * tile_rows).max(1))
+            .enumerate(),
+        output_stride,
+        tile_rows,
+        width,
+        height,
+    })
+}
+
+/// Iterator over independent rectangular tiles of an image transposition, see [transpose_tiles_2d]
+#[derive(Debug)]
+pub struct TransposeTiles2d<'a, V> {
+    bands: Enumerate<ChunksMut<'a, V>>,
+    pending: Vec<TransposeTile<'a, V>>,
+    output_stride: usize,
+    tile_rows: usize,
+    tile_columns: usize,
+    channels: usize,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, V: Copy> TransposeTiles2d<'a, V> {
+    /// Splits a band of output rows into tiles, stored in reverse order
+    fn split_band(&mut self, index: usize, band: &'a mut [V]) {
+        let columns_count = self.height.div_ceil(self.tile_columns);
+        let tile_length = self.tile_columns * self.channels;
+        let mut fragments: Vec<Vec<&'a mut [V]>> = (0..columns_count)
+            .map(|_| Vec::with_capacity(self.tile_rows))
+            .collect();
+        for row in band.chunks_mut(self.output_stride) {
+            let mut rest = &mut row[..self.height * self.channels];
+            for tile in fragments.iter_mut() {
+                let length = tile_length.min(rest.len());
+                let (head, tail) = core::mem::take(&mut rest).split_at_mut(length);
+                tile.push(head);
+                rest = tail;
+            }
+        }
+        self.pending = fragments
+            .into_iter()
+            .enumerate()
+            .rev()
+            .map(|(column, rows)| TransposeTile {
+                output: TileOutput::Fragments {
+                    rows,
+                    channels: self.channels,
+                },
+                output_stride: self.output_stride,
+                start_row: index * self.tile_rows,
+                start_column: column * self.tile_columns,
+                columns: self
+                    .tile_columns
+                    .min(self.height - column * self.tile_columns),
+                width: self.width,
+                height: self.height,
+            })
+            .collect();
+    }
+}
+
+impl<'a, V: Copy> Iterator for TransposeTiles2d<'a, V> {
+    type Item = TransposeTile<'a, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.pending.is_empty() {
+            let (index, band) = self.bands.next()?;
+            self.split_band(index, band);
+        }
+        self.pending.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.pending.len() + self.bands.len() * self.height.div_ceil(self.tile_columns);
+        (count, Some(count))
+    }
+}
+
+impl<V: Copy> ExactSizeIterator for TransposeTiles2d<'_, V> {}
+
+/// Splits transposition output into independent rectangular tiles
+///
+/// Each tile covers `tile_rows` rows and `tile_columns` columns of the transposed image,
+/// tiles on the last row and column may be smaller. Tiles are yielded row by row.
+/// Tiles run the same kernels as full image transposition through a scratch
+/// of the tile size, and may be sent to different threads.
+///
+/// # Arguments
+///
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Input image width
+/// * `height`: Input image height
+/// * `channels`: Channels count of the image, tiles must be transposed with the same layout
+/// * `tile_rows`: Number of output rows in a tile, at least 1
+/// * `tile_columns`: Number of output columns in a tile, at least 1
+///
+/// returns: Result<TransposeTiles2d, TransposeError>
+///
+pub fn transpose_tiles_2d<V: Copy>(
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    channels: usize,
+    tile_rows: usize,
+    tile_columns: usize,
+) -> Result<TransposeTiles2d<'_, V>, TransposeError> {
+    if output.len() != output_stride * width {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    if channels == 0 || output_stride < height * channels {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    let tile_rows = tile_rows.max(1);
+    Ok(TransposeTiles2d {
+        bands: output
+            .chunks_mut((output_stride * tile_rows).max(1))
+            .enumerate(),
+        pending: Vec::new(),
+        output_stride,
+        tile_rows,
+        tile_columns: tile_columns.max(1),
+        channels,
+        width,
+        height,
+    })
+}
@@ -26,7 +26,15 @@
  * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+use crate::cbcr8::transpose_cbcr8_chunked;
+use crate::float64_cbcr_invoker::execute_f64_cbcr;
+use crate::plane8::transpose_plane8_chunked;
+use crate::rgba16::transpose_rgba16_chunked;
+use crate::rgba8::transpose_rgba8_chunked;
+use crate::utils::cast_pod_slices;
 use crate::{FlipMode, FlopMode, TransposeError};
+use bytemuck::Pod;
+use core::mem::size_of;
 
 #[inline(always)]
 fn transpose_block<V: Copy, const FLOP: bool, const FLIP: bool>(
@@ -276,6 +284,167 @@ pub fn transpose_arbitrary<V: Copy>(
     if input.len() != input_stride * height {
         return Err(TransposeError::MismatchDimensions);
     }
+    transpose_arbitrary_view::<V>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Performs transposition of `Pod` types
+///
+/// Types of 1, 2, 4, 8 or 16 bytes are reinterpreted and transposed with kernels of planar,
+/// two channel and four channel 8-bit, four channel 16-bit or two channel `f64` images,
+/// others are transposed as in [transpose_arbitrary].
+///
+/// # Arguments
+///
+/// * `input`: Input date
+/// * `input_stride`: Input stride
+/// * `output`: Output data
+/// * `output_stride`: Output stride
+/// * `width`: Array width
+/// * `height`: Array height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn transpose_pod<V: Pod>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
+    transpose_pod_view::<V>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Same as public variant, but input may be a view into a larger image.
+pub(crate) fn transpose_pod_view<V: Pod>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    match size_of::<V>() {
+        1 => {
+            return transpose_plane8_chunked(
+                bytemuck::cast_slice(input),
+                input_stride,
+                bytemuck::cast_slice_mut(output),
+                output_stride,
+                width,
+                height,
+                flip_mode,
+                flop_mode,
+            )
+        }
+        2 => {
+            return transpose_cbcr8_chunked(
+                bytemuck::cast_slice(input),
+                input_stride * 2,
+                bytemuck::cast_slice_mut(output),
+                output_stride * 2,
+                width,
+                height,
+                flip_mode,
+                flop_mode,
+            )
+        }
+        4 => {
+            return transpose_rgba8_chunked(
+                bytemuck::cast_slice(input),
+                input_stride * 4,
+                bytemuck::cast_slice_mut(output),
+                output_stride * 4,
+                width,
+                height,
+                flip_mode,
+                flop_mode,
+            )
+        }
+        8 => {
+            if let Some((src, dst)) = cast_pod_slices::<V, u16>(input, output) {
+                return transpose_rgba16_chunked(
+                    src,
+                    input_stride * 4,
+                    dst,
+                    output_stride * 4,
+                    width,
+                    height,
+                    flip_mode,
+                    flop_mode,
+                );
+            }
+        }
+        16 => {
+            if let Some((src, dst)) = cast_pod_slices::<V, f64>(input, output) {
+                return execute_f64_cbcr(
+                    src,
+                    input_stride * 2,
+                    dst,
+                    output_stride * 2,
+                    width,
+                    height,
+                    flip_mode,
+                    flop_mode,
+                );
+            }
+        }
+        _ => {}
+    }
+    transpose_arbitrary_view::<V>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Same as [transpose_pod_view], but without size dispatch.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn transpose_arbitrary_view<V: Copy>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    if !crate::utils::fits_strided(input.len(), input_stride, height, width) {
+        return Err(TransposeError::MismatchDimensions);
+    }
     if output.len() != output_stride * width {
         return Err(TransposeError::MismatchDimensions);
     }
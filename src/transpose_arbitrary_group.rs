@@ -291,6 +291,33 @@ pub fn transpose_arbitrary_grouped<V: Copy, const N: usize>(
     if input.len() != input_stride * height {
         return Err(TransposeError::MismatchDimensions);
     }
+    transpose_arbitrary_grouped_view::<V, N>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Same as public variant, but input may be a view into a larger image.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn transpose_arbitrary_grouped_view<V: Copy, const N: usize>(
+    input: &[V],
+    input_stride: usize,
+    output: &mut [V],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    if !crate::utils::fits_strided(input.len(), input_stride, height, width * N) {
+        return Err(TransposeError::MismatchDimensions);
+    }
     if output.len() != output_stride * width {
         return Err(TransposeError::MismatchDimensions);
     }
@@ -28,9 +28,13 @@
  */
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "rayon")]
+use crate::parallel::transpose_par;
 use crate::plane16::transpose_plane16_chunked;
 use crate::rgba16::transpose_rgba16_chunked;
 use crate::transpose_arbitrary_group::transpose_arbitrary_grouped;
+#[cfg(feature = "rayon")]
+use crate::transpose_arbitrary_group::transpose_arbitrary_grouped_view;
 use crate::{FlipMode, FlopMode, TransposeError};
 
 /// Performs plane image transposition
@@ -58,6 +62,9 @@ pub fn transpose_plane16(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Result<(), TransposeError> {
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
     transpose_plane16_chunked(
         input,
         input_stride,
@@ -70,6 +77,48 @@ pub fn transpose_plane16(
     )
 }
 
+/// Performs plane image transposition using multiple threads
+///
+/// Work is split into bands of output rows processed by rayon thread pool,
+/// small images are transposed on the calling thread.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+#[cfg(feature = "rayon")]
+pub fn transpose_plane16_par(
+    input: &[u16],
+    input_stride: usize,
+    output: &mut [u16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_par::<u16, 1>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_plane16_chunked,
+    )
+}
+
 /// Performs plane with alpha image transposition
 ///
 /// # Arguments
@@ -107,6 +156,48 @@ pub fn transpose_plane16_with_alpha(
     )
 }
 
+/// Performs plane with alpha image transposition using multiple threads
+///
+/// Work is split into bands of output rows processed by rayon thread pool,
+/// small images are transposed on the calling thread.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+#[cfg(feature = "rayon")]
+pub fn transpose_plane16_with_alpha_par(
+    input: &[u16],
+    input_stride: usize,
+    output: &mut [u16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_par::<u16, 2>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_arbitrary_grouped_view::<u16, 2>,
+    )
+}
+
 /// Performs RGB image transposition
 ///
 /// # Arguments
@@ -144,6 +235,48 @@ pub fn transpose_rgb16(
     )
 }
 
+/// Performs RGB image transposition using multiple threads
+///
+/// Work is split into bands of output rows processed by rayon thread pool,
+/// small images are transposed on the calling thread.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+#[cfg(feature = "rayon")]
+pub fn transpose_rgb16_par(
+    input: &[u16],
+    input_stride: usize,
+    output: &mut [u16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_par::<u16, 3>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_arbitrary_grouped_view::<u16, 3>,
+    )
+}
+
 /// Performs RGBA image transposition
 ///
 /// # Arguments
@@ -169,6 +302,9 @@ pub fn transpose_rgba16(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Result<(), TransposeError> {
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
     transpose_rgba16_chunked(
         input,
         input_stride,
@@ -180,3 +316,45 @@ pub fn transpose_rgba16(
         flop_mode,
     )
 }
+
+/// Performs RGBA image transposition using multiple threads
+///
+/// Work is split into bands of output rows processed by rayon thread pool,
+/// small images are transposed on the calling thread.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+#[cfg(feature = "rayon")]
+pub fn transpose_rgba16_par(
+    input: &[u16],
+    input_stride: usize,
+    output: &mut [u16],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_par::<u16, 4>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_rgba16_chunked,
+    )
+}
@@ -28,9 +28,13 @@
  */
 #![forbid(unsafe_code)]
 use crate::cbcr8::transpose_cbcr8_chunked;
+#[cfg(feature = "rayon")]
+use crate::parallel::transpose_par;
 use crate::plane8::transpose_plane8_chunked;
 use crate::rgba8::transpose_rgba8_chunked;
 use crate::transpose_arbitrary_group::transpose_arbitrary_grouped;
+#[cfg(feature = "rayon")]
+use crate::transpose_arbitrary_group::transpose_arbitrary_grouped_view;
 use crate::utils::FlopMode;
 use crate::{FlipMode, TransposeError};
 
@@ -59,6 +63,9 @@ pub fn transpose_plane(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Result<(), TransposeError> {
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
     transpose_plane8_chunked(
         input,
         input_stride,
@@ -71,6 +78,48 @@ pub fn transpose_plane(
     )
 }
 
+/// Performs plane image transposition using multiple threads
+///
+/// Work is split into bands of output rows processed by rayon thread pool,
+/// small images are transposed on the calling thread.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+#[cfg(feature = "rayon")]
+pub fn transpose_plane_par(
+    input: &[u8],
+    input_stride: usize,
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_par::<u8, 1>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_plane8_chunked,
+    )
+}
+
 /// Performs plane with alpha image transposition
 ///
 /// # Arguments
@@ -96,6 +145,9 @@ pub fn transpose_plane_with_alpha(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Result<(), TransposeError> {
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
     transpose_cbcr8_chunked(
         input,
         input_stride,
@@ -108,6 +160,48 @@ pub fn transpose_plane_with_alpha(
     )
 }
 
+/// Performs plane with alpha image transposition using multiple threads
+///
+/// Work is split into bands of output rows processed by rayon thread pool,
+/// small images are transposed on the calling thread.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+#[cfg(feature = "rayon")]
+pub fn transpose_plane_with_alpha_par(
+    input: &[u8],
+    input_stride: usize,
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_par::<u8, 2>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_cbcr8_chunked,
+    )
+}
+
 /// Performs RGB image transposition
 ///
 /// # Arguments
@@ -145,6 +239,48 @@ pub fn transpose_rgb(
     )
 }
 
+/// Performs RGB image transposition using multiple threads
+///
+/// Work is split into bands of output rows processed by rayon thread pool,
+/// small images are transposed on the calling thread.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+#[cfg(feature = "rayon")]
+pub fn transpose_rgb_par(
+    input: &[u8],
+    input_stride: usize,
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_par::<u8, 3>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_arbitrary_grouped_view::<u8, 3>,
+    )
+}
+
 /// Performs RGBA image transposition
 ///
 /// # Arguments
@@ -170,6 +306,9 @@ pub fn transpose_rgba(
     flip_mode: FlipMode,
     flop_mode: FlopMode,
 ) -> Result<(), TransposeError> {
+    if input.len() != input_stride * height {
+        return Err(TransposeError::MismatchDimensions);
+    }
     transpose_rgba8_chunked(
         input,
         input_stride,
@@ -181,3 +320,45 @@ pub fn transpose_rgba(
         flop_mode,
     )
 }
+
+/// Performs RGBA image transposition using multiple threads
+///
+/// Work is split into bands of output rows processed by rayon thread pool,
+/// small images are transposed on the calling thread.
+///
+/// # Arguments
+///
+/// * `input`: Input data
+/// * `input_stride`: Input data stride
+/// * `output`: Output data
+/// * `output_stride`: Output data stride
+/// * `width`: Image width
+/// * `height`: Image height
+/// * `flip_mode`: see [FlipMode]
+/// * `flop_mode`: see [FlopMode]
+///
+/// returns: Result<(), TransposeError>
+///
+#[cfg(feature = "rayon")]
+pub fn transpose_rgba_par(
+    input: &[u8],
+    input_stride: usize,
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    flip_mode: FlipMode,
+    flop_mode: FlopMode,
+) -> Result<(), TransposeError> {
+    transpose_par::<u8, 4>(
+        input,
+        input_stride,
+        output,
+        output_stride,
+        width,
+        height,
+        flip_mode,
+        flop_mode,
+        transpose_rgba8_chunked,
+    )
+}
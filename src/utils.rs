@@ -27,17 +27,22 @@
  * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 #![forbid(unsafe_code)]
-use std::error::Error;
-use std::fmt::{Display, Formatter};
+use bytemuck::Pod;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
 
 #[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
+#[non_exhaustive]
 pub enum TransposeError {
     MismatchDimensions,
     InvalidArraySize,
+    InvalidBitDepth,
+    InvalidPermutation,
+    InvalidStructLayout,
 }
 
 impl Display for TransposeError {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
             TransposeError::MismatchDimensions => {
                 f.write_str("Source and target dimensions must match")
@@ -45,6 +50,15 @@ impl Display for TransposeError {
             TransposeError::InvalidArraySize => {
                 f.write_str("Vector size must match provided width and height")
             }
+            TransposeError::InvalidBitDepth => {
+                f.write_str("Sample value exceeds declared bit depth")
+            }
+            TransposeError::InvalidPermutation => {
+                f.write_str("Permutation must list every axis exactly once")
+            }
+            TransposeError::InvalidStructLayout => {
+                f.write_str("Struct must consist of exactly declared number of fields of one type")
+            }
         }
     }
 }
@@ -64,3 +78,199 @@ pub enum FlopMode {
     NoFlop,
     Flop,
 }
+
+/// Declares orientation change of a frame
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Default)]
+pub enum Orientation {
+    /// Keeps frame as is
+    #[default]
+    Identity,
+    /// Rotates frame by 90 degrees clockwise
+    Rotate90,
+    /// Rotates frame by 180 degrees
+    Rotate180,
+    /// Rotates frame by 270 degrees clockwise
+    Rotate270,
+    /// Mirrors frame horizontally
+    Flip,
+    /// Mirrors frame vertically
+    Flop,
+    /// Mirrors frame along main diagonal
+    Transpose,
+    /// Mirrors frame along anti-diagonal
+    Transverse,
+}
+
+impl Orientation {
+    /// Returns `true` if width and height of the frame are swapped
+    pub fn swaps_dimensions(self) -> bool {
+        match self {
+            Orientation::Identity
+            | Orientation::Rotate180
+            | Orientation::Flip
+            | Orientation::Flop => false,
+            Orientation::Rotate90
+            | Orientation::Rotate270
+            | Orientation::Transpose
+            | Orientation::Transverse => true,
+        }
+    }
+
+    /// Returns dimensions of the oriented frame
+    pub fn oriented_size(self, width: usize, height: usize) -> (usize, usize) {
+        if self.swaps_dimensions() {
+            (height, width)
+        } else {
+            (width, height)
+        }
+    }
+}
+
+/// Declares position of subsampled chroma samples relative to luma
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
+pub enum ChromaSiting {
+    /// Chroma is centered between luma samples in both directions (JPEG, MPEG-1)
+    Center,
+    /// Chroma is co-sited with left luma column and centered vertically (MPEG-2, H.264)
+    Left,
+    /// Chroma is co-sited with top left luma sample (BT.2020)
+    TopLeft,
+}
+
+impl ChromaSiting {
+    /// Horizontal and vertical chroma offsets in halves of luma sample
+    pub(crate) fn offsets(self) -> (usize, usize) {
+        match self {
+            ChromaSiting::Center => (1, 1),
+            ChromaSiting::Left => (0, 1),
+            ChromaSiting::TopLeft => (0, 0),
+        }
+    }
+}
+
+/// Declares handling of samples that don't fit declared bit depth
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Default)]
+pub enum BitDepthCheck {
+    /// Samples are copied as is
+    #[default]
+    Unchecked,
+    /// Source is checked before anything is written,
+    /// [TransposeError::InvalidBitDepth] is returned on out of range sample
+    Verify,
+    /// Bits outside of bit depth are cleared in output
+    Mask,
+}
+
+/// Checks that `rows` rows of `row_length` elements with `stride` fit into the buffer.
+///
+/// Unlike strict `stride * rows` this also accepts views into a larger image,
+/// e.g. when the last row is not padded up to the stride.
+#[inline]
+pub(crate) fn fits_strided(length: usize, stride: usize, rows: usize, row_length: usize) -> bool {
+    rows == 0 || length >= stride * (rows - 1) + row_length
+}
+
+/// Checks if x86 CPU feature is available.
+///
+/// With `std` it is detected at runtime, otherwise only features
+/// enabled at compile time with `target_feature` are considered.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(unused_macros)]
+macro_rules! has_x86_feature {
+    ($feature:tt) => {{
+        #[cfg(feature = "std")]
+        {
+            std::arch::is_x86_feature_detected!($feature)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = $feature)
+        }
+    }};
+}
+
+/// Checks if aarch64 CPU feature is available.
+///
+/// With `std` it is detected at runtime, otherwise only features
+/// enabled at compile time with `target_feature` are considered.
+#[cfg(target_arch = "aarch64")]
+#[allow(unused_macros)]
+macro_rules! has_aarch64_feature {
+    ($feature:tt) => {{
+        #[cfg(feature = "std")]
+        {
+            std::arch::is_aarch64_feature_detected!($feature)
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            cfg!(target_feature = $feature)
+        }
+    }};
+}
+
+/// Calls `$executor::<FLOP, FLIP>` matching requested flip and flop modes,
+/// so the kernel is called directly and may be inlined.
+#[allow(unused_macros)]
+macro_rules! dispatch_flip_flop {
+    ($flip_mode:expr, $flop_mode:expr, $executor:ident, $($arg:expr),+ $(,)?) => {
+        match $flip_mode {
+            $crate::FlipMode::NoFlip => match $flop_mode {
+                $crate::FlopMode::NoFlop => $executor::<false, false>($($arg),+),
+                $crate::FlopMode::Flop => $executor::<true, false>($($arg),+),
+            },
+            $crate::FlipMode::Flip => match $flop_mode {
+                $crate::FlopMode::NoFlop => $executor::<false, true>($($arg),+),
+                $crate::FlopMode::Flop => $executor::<true, true>($($arg),+),
+            },
+        }
+    };
+}
+
+/// Reinterprets input and output as slices of `U`, returns `None` when
+/// alignment of `V` doesn't allow it.
+#[inline]
+pub(crate) fn cast_pod_slices<'a, 'b, V: Pod, U: Pod>(
+    input: &'a [V],
+    output: &'b mut [V],
+) -> Option<(&'a [U], &'b mut [U])> {
+    match (
+        bytemuck::try_cast_slice::<V, U>(input),
+        bytemuck::try_cast_slice_mut::<V, U>(output),
+    ) {
+        (Ok(input), Ok(output)) => Some((input, output)),
+        _ => None,
+    }
+}
+
+/// Calls `$executor::<U>` with `Pod` slices reinterpreted as unsigned integers
+/// of the same size, so any type shares kernels of the integer types.
+///
+/// Evaluates to `None` when size isn't 1, 2, 4, 8 or 16 bytes or slices
+/// aren't aligned enough for the integer type.
+macro_rules! dispatch_pod_size {
+    ($v:ty, $executor:ident, $input:expr, $input_stride:expr, $output:expr, $output_stride:expr $(, $arg:expr)* $(,)?) => {
+        match core::mem::size_of::<$v>() {
+            1 => $crate::utils::cast_pod_slices::<$v, u8>($input, $output)
+                .map(|(src, dst)| $executor::<u8>(src, $input_stride, dst, $output_stride $(, $arg)*)),
+            2 => $crate::utils::cast_pod_slices::<$v, u16>($input, $output)
+                .map(|(src, dst)| $executor::<u16>(src, $input_stride, dst, $output_stride $(, $arg)*)),
+            4 => $crate::utils::cast_pod_slices::<$v, u32>($input, $output)
+                .map(|(src, dst)| $executor::<u32>(src, $input_stride, dst, $output_stride $(, $arg)*)),
+            8 => $crate::utils::cast_pod_slices::<$v, u64>($input, $output)
+                .map(|(src, dst)| $executor::<u64>(src, $input_stride, dst, $output_stride $(, $arg)*)),
+            16 => $crate::utils::cast_pod_slices::<$v, [u64; 2]>($input, $output)
+                .map(|(src, dst)| $executor::<[u64; 2]>(src, $input_stride, dst, $output_stride $(, $arg)*)),
+            _ => None,
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use dispatch_flip_flop;
+pub(crate) use dispatch_pod_size;
+#[cfg(target_arch = "aarch64")]
+#[allow(unused_imports)]
+pub(crate) use has_aarch64_feature;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(unused_imports)]
+pub(crate) use has_x86_feature;
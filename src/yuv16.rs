@@ -0,0 +1,453 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::orientation::{check_plane, orient_cbcr16, orient_plane16};
+use crate::{BitDepthCheck, Orientation, TransposeError};
+
+/// Valid bits of 10-bit samples stored in least significant bits
+const LSB_10_BITS: u16 = (1 << 10) - 1;
+/// Valid bits of 10-bit samples stored in most significant bits
+const MSB_10_BITS: u16 = !((1 << 6) - 1);
+
+fn verify_plane(
+    plane: &[u16],
+    stride: usize,
+    width: usize,
+    valid_bits: u16,
+) -> Result<(), TransposeError> {
+    if width == 0 {
+        return Ok(());
+    }
+    for row in plane.chunks_exact(stride) {
+        if row[..width].iter().any(|&x| x & !valid_bits != 0) {
+            return Err(TransposeError::InvalidBitDepth);
+        }
+    }
+    Ok(())
+}
+
+fn mask_plane(plane: &mut [u16], stride: usize, width: usize, valid_bits: u16) {
+    if width == 0 {
+        return;
+    }
+    for row in plane.chunks_exact_mut(stride) {
+        for x in row[..width].iter_mut() {
+            *x &= valid_bits;
+        }
+    }
+}
+
+fn rotate_semi_planar16(
+    src_y: &[u16],
+    src_stride_y: usize,
+    src_uv: &[u16],
+    src_stride_uv: usize,
+    dst_y: &mut [u16],
+    dst_stride_y: usize,
+    dst_uv: &mut [u16],
+    dst_stride_uv: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+    valid_bits: u16,
+    bit_depth_check: BitDepthCheck,
+) -> Result<(), TransposeError> {
+    let chroma_width = width.div_ceil(2);
+    let chroma_height = height.div_ceil(2);
+    let (dst_width, dst_height) = orientation.oriented_size(width, height);
+    let (dst_chroma_width, dst_chroma_height) =
+        orientation.oriented_size(chroma_width, chroma_height);
+
+    check_plane(src_y.len(), src_stride_y, width, height)?;
+    check_plane(src_uv.len(), src_stride_uv, chroma_width * 2, chroma_height)?;
+    check_plane(dst_y.len(), dst_stride_y, dst_width, dst_height)?;
+    check_plane(
+        dst_uv.len(),
+        dst_stride_uv,
+        dst_chroma_width * 2,
+        dst_chroma_height,
+    )?;
+
+    if bit_depth_check == BitDepthCheck::Verify {
+        verify_plane(src_y, src_stride_y, width, valid_bits)?;
+        verify_plane(src_uv, src_stride_uv, chroma_width * 2, valid_bits)?;
+    }
+
+    orient_plane16(
+        src_y,
+        src_stride_y,
+        dst_y,
+        dst_stride_y,
+        width,
+        height,
+        orientation,
+    )?;
+    orient_cbcr16(
+        src_uv,
+        src_stride_uv,
+        dst_uv,
+        dst_stride_uv,
+        chroma_width,
+        chroma_height,
+        orientation,
+    )?;
+
+    if bit_depth_check == BitDepthCheck::Mask {
+        mask_plane(dst_y, dst_stride_y, dst_width, valid_bits);
+        mask_plane(dst_uv, dst_stride_uv, dst_chroma_width * 2, valid_bits);
+    }
+    Ok(())
+}
+
+fn rotate_planar16(
+    src_y: &[u16],
+    src_stride_y: usize,
+    src_u: &[u16],
+    src_stride_u: usize,
+    src_v: &[u16],
+    src_stride_v: usize,
+    dst_y: &mut [u16],
+    dst_stride_y: usize,
+    dst_u: &mut [u16],
+    dst_stride_u: usize,
+    dst_v: &mut [u16],
+    dst_stride_v: usize,
+    width: usize,
+    height: usize,
+    chroma_height: usize,
+    orientation: Orientation,
+    valid_bits: u16,
+    bit_depth_check: BitDepthCheck,
+) -> Result<(), TransposeError> {
+    let chroma_width = width.div_ceil(2);
+    let (dst_width, dst_height) = orientation.oriented_size(width, height);
+    let (dst_chroma_width, dst_chroma_height) =
+        orientation.oriented_size(chroma_width, chroma_height);
+
+    check_plane(src_y.len(), src_stride_y, width, height)?;
+    check_plane(src_u.len(), src_stride_u, chroma_width, chroma_height)?;
+    check_plane(src_v.len(), src_stride_v, chroma_width, chroma_height)?;
+    check_plane(dst_y.len(), dst_stride_y, dst_width, dst_height)?;
+    check_plane(
+        dst_u.len(),
+        dst_stride_u,
+        dst_chroma_width,
+        dst_chroma_height,
+    )?;
+    check_plane(
+        dst_v.len(),
+        dst_stride_v,
+        dst_chroma_width,
+        dst_chroma_height,
+    )?;
+
+    if bit_depth_check == BitDepthCheck::Verify {
+        verify_plane(src_y, src_stride_y, width, valid_bits)?;
+        verify_plane(src_u, src_stride_u, chroma_width, valid_bits)?;
+        verify_plane(src_v, src_stride_v, chroma_width, valid_bits)?;
+    }
+
+    orient_plane16(
+        src_y,
+        src_stride_y,
+        dst_y,
+        dst_stride_y,
+        width,
+        height,
+        orientation,
+    )?;
+    orient_plane16(
+        src_u,
+        src_stride_u,
+        dst_u,
+        dst_stride_u,
+        chroma_width,
+        chroma_height,
+        orientation,
+    )?;
+    orient_plane16(
+        src_v,
+        src_stride_v,
+        dst_v,
+        dst_stride_v,
+        chroma_width,
+        chroma_height,
+        orientation,
+    )?;
+
+    if bit_depth_check == BitDepthCheck::Mask {
+        mask_plane(dst_y, dst_stride_y, dst_width, valid_bits);
+        mask_plane(dst_u, dst_stride_u, dst_chroma_width, valid_bits);
+        mask_plane(dst_v, dst_stride_v, dst_chroma_width, valid_bits);
+    }
+    Ok(())
+}
+
+/// Rotates or mirrors semi-planar 10-bit YUV 4:2:0 (P010) frame
+///
+/// Samples are stored in the most significant bits of 16-bit containers.
+/// Interleaved UV plane has `(width + 1) / 2` x `(height + 1) / 2` pairs,
+/// strides are in elements.
+/// Destination frame has dimensions returned by [Orientation::oriented_size].
+///
+/// # Arguments
+///
+/// * `src_y`: Source luma plane
+/// * `src_stride_y`: Source luma plane stride
+/// * `src_uv`: Source interleaved UV plane
+/// * `src_stride_uv`: Source UV plane stride
+/// * `dst_y`: Destination luma plane
+/// * `dst_stride_y`: Destination luma plane stride
+/// * `dst_uv`: Destination interleaved UV plane
+/// * `dst_stride_uv`: Destination UV plane stride
+/// * `width`: Source frame width
+/// * `height`: Source frame height
+/// * `orientation`: see [Orientation]
+/// * `bit_depth_check`: see [BitDepthCheck]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate_p010(
+    src_y: &[u16],
+    src_stride_y: usize,
+    src_uv: &[u16],
+    src_stride_uv: usize,
+    dst_y: &mut [u16],
+    dst_stride_y: usize,
+    dst_uv: &mut [u16],
+    dst_stride_uv: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+    bit_depth_check: BitDepthCheck,
+) -> Result<(), TransposeError> {
+    rotate_semi_planar16(
+        src_y,
+        src_stride_y,
+        src_uv,
+        src_stride_uv,
+        dst_y,
+        dst_stride_y,
+        dst_uv,
+        dst_stride_uv,
+        width,
+        height,
+        orientation,
+        MSB_10_BITS,
+        bit_depth_check,
+    )
+}
+
+/// Rotates or mirrors semi-planar 16-bit YUV 4:2:0 (P016) frame
+///
+/// Interleaved UV plane has `(width + 1) / 2` x `(height + 1) / 2` pairs,
+/// strides are in elements.
+/// Destination frame has dimensions returned by [Orientation::oriented_size].
+///
+/// # Arguments
+///
+/// * `src_y`: Source luma plane
+/// * `src_stride_y`: Source luma plane stride
+/// * `src_uv`: Source interleaved UV plane
+/// * `src_stride_uv`: Source UV plane stride
+/// * `dst_y`: Destination luma plane
+/// * `dst_stride_y`: Destination luma plane stride
+/// * `dst_uv`: Destination interleaved UV plane
+/// * `dst_stride_uv`: Destination UV plane stride
+/// * `width`: Source frame width
+/// * `height`: Source frame height
+/// * `orientation`: see [Orientation]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate_p016(
+    src_y: &[u16],
+    src_stride_y: usize,
+    src_uv: &[u16],
+    src_stride_uv: usize,
+    dst_y: &mut [u16],
+    dst_stride_y: usize,
+    dst_uv: &mut [u16],
+    dst_stride_uv: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+) -> Result<(), TransposeError> {
+    rotate_semi_planar16(
+        src_y,
+        src_stride_y,
+        src_uv,
+        src_stride_uv,
+        dst_y,
+        dst_stride_y,
+        dst_uv,
+        dst_stride_uv,
+        width,
+        height,
+        orientation,
+        u16::MAX,
+        BitDepthCheck::Unchecked,
+    )
+}
+
+/// Rotates or mirrors planar 10-bit YUV 4:2:0 (I010) frame
+///
+/// Samples are stored in the least significant bits of 16-bit containers.
+/// Chroma planes have `(width + 1) / 2` x `(height + 1) / 2` samples, strides are in elements.
+/// Destination frame has dimensions returned by [Orientation::oriented_size].
+///
+/// # Arguments
+///
+/// * `src_y`: Source luma plane
+/// * `src_stride_y`: Source luma plane stride
+/// * `src_u`: Source U plane
+/// * `src_stride_u`: Source U plane stride
+/// * `src_v`: Source V plane
+/// * `src_stride_v`: Source V plane stride
+/// * `dst_y`: Destination luma plane
+/// * `dst_stride_y`: Destination luma plane stride
+/// * `dst_u`: Destination U plane
+/// * `dst_stride_u`: Destination U plane stride
+/// * `dst_v`: Destination V plane
+/// * `dst_stride_v`: Destination V plane stride
+/// * `width`: Source frame width
+/// * `height`: Source frame height
+/// * `orientation`: see [Orientation]
+/// * `bit_depth_check`: see [BitDepthCheck]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate_i010(
+    src_y: &[u16],
+    src_stride_y: usize,
+    src_u: &[u16],
+    src_stride_u: usize,
+    src_v: &[u16],
+    src_stride_v: usize,
+    dst_y: &mut [u16],
+    dst_stride_y: usize,
+    dst_u: &mut [u16],
+    dst_stride_u: usize,
+    dst_v: &mut [u16],
+    dst_stride_v: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+    bit_depth_check: BitDepthCheck,
+) -> Result<(), TransposeError> {
+    rotate_planar16(
+        src_y,
+        src_stride_y,
+        src_u,
+        src_stride_u,
+        src_v,
+        src_stride_v,
+        dst_y,
+        dst_stride_y,
+        dst_u,
+        dst_stride_u,
+        dst_v,
+        dst_stride_v,
+        width,
+        height,
+        height.div_ceil(2),
+        orientation,
+        LSB_10_BITS,
+        bit_depth_check,
+    )
+}
+
+/// Rotates or mirrors planar 10-bit YUV 4:2:2 (I210) frame
+///
+/// Samples are stored in the least significant bits of 16-bit containers.
+/// Chroma planes have `(width + 1) / 2` x `height` samples, strides are in elements.
+/// Destination frame has dimensions returned by [Orientation::oriented_size],
+/// when dimensions are swapped chroma is subsampled vertically,
+/// and destination chroma planes have `(width + 1) / 2` rows of `height` samples.
+///
+/// # Arguments
+///
+/// * `src_y`: Source luma plane
+/// * `src_stride_y`: Source luma plane stride
+/// * `src_u`: Source U plane
+/// * `src_stride_u`: Source U plane stride
+/// * `src_v`: Source V plane
+/// * `src_stride_v`: Source V plane stride
+/// * `dst_y`: Destination luma plane
+/// * `dst_stride_y`: Destination luma plane stride
+/// * `dst_u`: Destination U plane
+/// * `dst_stride_u`: Destination U plane stride
+/// * `dst_v`: Destination V plane
+/// * `dst_stride_v`: Destination V plane stride
+/// * `width`: Source frame width
+/// * `height`: Source frame height
+/// * `orientation`: see [Orientation]
+/// * `bit_depth_check`: see [BitDepthCheck]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate_i210(
+    src_y: &[u16],
+    src_stride_y: usize,
+    src_u: &[u16],
+    src_stride_u: usize,
+    src_v: &[u16],
+    src_stride_v: usize,
+    dst_y: &mut [u16],
+    dst_stride_y: usize,
+    dst_u: &mut [u16],
+    dst_stride_u: usize,
+    dst_v: &mut [u16],
+    dst_stride_v: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+    bit_depth_check: BitDepthCheck,
+) -> Result<(), TransposeError> {
+    rotate_planar16(
+        src_y,
+        src_stride_y,
+        src_u,
+        src_stride_u,
+        src_v,
+        src_stride_v,
+        dst_y,
+        dst_stride_y,
+        dst_u,
+        dst_stride_u,
+        dst_v,
+        dst_stride_v,
+        width,
+        height,
+        height,
+        orientation,
+        LSB_10_BITS,
+        bit_depth_check,
+    )
+}
@@ -0,0 +1,641 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::orientation::{check_plane, orient_cbcr8, orient_plane8};
+use crate::tile::TransposeTile;
+use crate::{ChromaSiting, FlipMode, FlopMode, Orientation, TransposeError};
+use alloc::vec;
+
+/// Rotates or mirrors planar YUV 4:2:0 (I420) frame
+///
+/// Chroma planes have `(width + 1) / 2` x `(height + 1) / 2` samples.
+/// Destination frame has dimensions returned by [Orientation::oriented_size].
+/// Chroma samples are moved by index, see [rotate_i420_with_siting] to keep chroma siting.
+/// YV12 frames are supported by swapping `u` and `v` planes.
+///
+/// # Arguments
+///
+/// * `src_y`: Source luma plane
+/// * `src_stride_y`: Source luma plane stride
+/// * `src_u`: Source U plane
+/// * `src_stride_u`: Source U plane stride
+/// * `src_v`: Source V plane
+/// * `src_stride_v`: Source V plane stride
+/// * `dst_y`: Destination luma plane
+/// * `dst_stride_y`: Destination luma plane stride
+/// * `dst_u`: Destination U plane
+/// * `dst_stride_u`: Destination U plane stride
+/// * `dst_v`: Destination V plane
+/// * `dst_stride_v`: Destination V plane stride
+/// * `width`: Source frame width
+/// * `height`: Source frame height
+/// * `orientation`: see [Orientation]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate_i420(
+    src_y: &[u8],
+    src_stride_y: usize,
+    src_u: &[u8],
+    src_stride_u: usize,
+    src_v: &[u8],
+    src_stride_v: usize,
+    dst_y: &mut [u8],
+    dst_stride_y: usize,
+    dst_u: &mut [u8],
+    dst_stride_u: usize,
+    dst_v: &mut [u8],
+    dst_stride_v: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+) -> Result<(), TransposeError> {
+    let chroma_width = width.div_ceil(2);
+    let chroma_height = height.div_ceil(2);
+    let (dst_width, dst_height) = orientation.oriented_size(width, height);
+    let (dst_chroma_width, dst_chroma_height) =
+        orientation.oriented_size(chroma_width, chroma_height);
+
+    check_plane(src_y.len(), src_stride_y, width, height)?;
+    check_plane(src_u.len(), src_stride_u, chroma_width, chroma_height)?;
+    check_plane(src_v.len(), src_stride_v, chroma_width, chroma_height)?;
+    check_plane(dst_y.len(), dst_stride_y, dst_width, dst_height)?;
+    check_plane(
+        dst_u.len(),
+        dst_stride_u,
+        dst_chroma_width,
+        dst_chroma_height,
+    )?;
+    check_plane(
+        dst_v.len(),
+        dst_stride_v,
+        dst_chroma_width,
+        dst_chroma_height,
+    )?;
+
+    orient_plane8(
+        src_y,
+        src_stride_y,
+        dst_y,
+        dst_stride_y,
+        width,
+        height,
+        orientation,
+    )?;
+    orient_plane8(
+        src_u,
+        src_stride_u,
+        dst_u,
+        dst_stride_u,
+        chroma_width,
+        chroma_height,
+        orientation,
+    )?;
+    orient_plane8(
+        src_v,
+        src_stride_v,
+        dst_v,
+        dst_stride_v,
+        chroma_width,
+        chroma_height,
+        orientation,
+    )
+}
+
+/// Returns for destination x and y axes if source axis is read backwards
+fn mirrored_axes(orientation: Orientation) -> (bool, bool) {
+    match orientation {
+        Orientation::Identity => (false, false),
+        Orientation::Rotate90 => (true, false),
+        Orientation::Rotate180 => (true, true),
+        Orientation::Rotate270 => (false, true),
+        Orientation::Flip => (true, false),
+        Orientation::Flop => (false, true),
+        Orientation::Transpose => (false, false),
+        Orientation::Transverse => (true, true),
+    }
+}
+
+/// Returns chroma shift along destination axis in quarters of chroma sample
+/// that remains after chroma plane is oriented by sample index.
+///
+/// Offsets are in halves of luma sample, `luma_length` is length of the source axis.
+fn residual_shift(
+    luma_length: usize,
+    source_offset: usize,
+    target_offset: usize,
+    mirrored: bool,
+) -> isize {
+    if mirrored {
+        let chroma_length = luma_length.div_ceil(2);
+        4 * (chroma_length as isize - 1) + source_offset as isize + target_offset as isize
+            - 2 * (luma_length as isize - 1)
+    } else {
+        target_offset as isize - source_offset as isize
+    }
+}
+
+/// Returns taps and weight of the second tap in quarters for position in quarters of sample
+fn taps(position: isize, length: usize) -> (usize, usize, u16) {
+    let last = length as isize - 1;
+    let index = position.div_euclid(4);
+    let weight = position.rem_euclid(4) as u16;
+    (
+        index.clamp(0, last) as usize,
+        (index + 1).clamp(0, last) as usize,
+        weight,
+    )
+}
+
+/// Shifts plane content by fraction of sample with bilinear interpolation,
+/// shifts are in quarters of sample
+fn shift_plane(
+    plane: &mut [u8],
+    stride: usize,
+    width: usize,
+    height: usize,
+    shift_x: isize,
+    shift_y: isize,
+) {
+    if width == 0 || height == 0 {
+        return;
+    }
+    let mut source = vec![0u8; width * height];
+    for (dst, src) in source
+        .chunks_exact_mut(width)
+        .zip(plane.chunks_exact(stride))
+    {
+        dst.copy_from_slice(&src[..width]);
+    }
+    for (y, dst) in plane.chunks_exact_mut(stride).enumerate() {
+        let (y0, y1, wy) = taps(y as isize * 4 + shift_y, height);
+        let row0 = &source[y0 * width..(y0 + 1) * width];
+        let row1 = &source[y1 * width..(y1 + 1) * width];
+        for (x, dst) in dst[..width].iter_mut().enumerate() {
+            let (x0, x1, wx) = taps(x as isize * 4 + shift_x, width);
+            let top = row0[x0] as u16 * (4 - wx) + row0[x1] as u16 * wx;
+            let bottom = row1[x0] as u16 * (4 - wx) + row1[x1] as u16 * wx;
+            *dst = ((top * (4 - wy) + bottom * wy + 8) >> 4) as u8;
+        }
+    }
+}
+
+/// Rotates or mirrors planar YUV 4:2:0 (I420) frame keeping chroma siting
+///
+/// Same as [rotate_i420], but chroma is resampled with bilinear filter where
+/// mirroring or transposing moves chroma samples off the declared siting,
+/// e.g. horizontal flip of even width frame with [ChromaSiting::Left].
+///
+/// # Arguments
+///
+/// * `src_y`: Source luma plane
+/// * `src_stride_y`: Source luma plane stride
+/// * `src_u`: Source U plane
+/// * `src_stride_u`: Source U plane stride
+/// * `src_v`: Source V plane
+/// * `src_stride_v`: Source V plane stride
+/// * `dst_y`: Destination luma plane
+/// * `dst_stride_y`: Destination luma plane stride
+/// * `dst_u`: Destination U plane
+/// * `dst_stride_u`: Destination U plane stride
+/// * `dst_v`: Destination V plane
+/// * `dst_stride_v`: Destination V plane stride
+/// * `width`: Source frame width
+/// * `height`: Source frame height
+/// * `orientation`: see [Orientation]
+/// * `siting`: Chroma siting of source and destination frame, see [ChromaSiting]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate_i420_with_siting(
+    src_y: &[u8],
+    src_stride_y: usize,
+    src_u: &[u8],
+    src_stride_u: usize,
+    src_v: &[u8],
+    src_stride_v: usize,
+    dst_y: &mut [u8],
+    dst_stride_y: usize,
+    dst_u: &mut [u8],
+    dst_stride_u: usize,
+    dst_v: &mut [u8],
+    dst_stride_v: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+    siting: ChromaSiting,
+) -> Result<(), TransposeError> {
+    rotate_i420(
+        src_y,
+        src_stride_y,
+        src_u,
+        src_stride_u,
+        src_v,
+        src_stride_v,
+        dst_y,
+        dst_stride_y,
+        dst_u,
+        dst_stride_u,
+        dst_v,
+        dst_stride_v,
+        width,
+        height,
+        orientation,
+    )?;
+
+    let (offset_x, offset_y) = siting.offsets();
+    let (mirrored_x, mirrored_y) = mirrored_axes(orientation);
+    let (shift_x, shift_y) = if orientation.swaps_dimensions() {
+        (
+            residual_shift(height, offset_y, offset_x, mirrored_x),
+            residual_shift(width, offset_x, offset_y, mirrored_y),
+        )
+    } else {
+        (
+            residual_shift(width, offset_x, offset_x, mirrored_x),
+            residual_shift(height, offset_y, offset_y, mirrored_y),
+        )
+    };
+    if shift_x == 0 && shift_y == 0 {
+        return Ok(());
+    }
+
+    let (dst_chroma_width, dst_chroma_height) =
+        orientation.oriented_size(width.div_ceil(2), height.div_ceil(2));
+    shift_plane(
+        dst_u,
+        dst_stride_u,
+        dst_chroma_width,
+        dst_chroma_height,
+        shift_x,
+        shift_y,
+    );
+    shift_plane(
+        dst_v,
+        dst_stride_v,
+        dst_chroma_width,
+        dst_chroma_height,
+        shift_x,
+        shift_y,
+    );
+    Ok(())
+}
+
+/// Rotates or mirrors semi-planar YUV 4:2:0 (NV12) frame
+///
+/// Interleaved UV plane has `(width + 1) / 2` x `(height + 1) / 2` pairs,
+/// its stride is in bytes.
+/// Destination frame has dimensions returned by [Orientation::oriented_size].
+///
+/// # Arguments
+///
+/// * `src_y`: Source luma plane
+/// * `src_stride_y`: Source luma plane stride
+/// * `src_uv`: Source interleaved UV plane
+/// * `src_stride_uv`: Source UV plane stride
+/// * `dst_y`: Destination luma plane
+/// * `dst_stride_y`: Destination luma plane stride
+/// * `dst_uv`: Destination interleaved UV plane
+/// * `dst_stride_uv`: Destination UV plane stride
+/// * `width`: Source frame width
+/// * `height`: Source frame height
+/// * `orientation`: see [Orientation]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate_nv12(
+    src_y: &[u8],
+    src_stride_y: usize,
+    src_uv: &[u8],
+    src_stride_uv: usize,
+    dst_y: &mut [u8],
+    dst_stride_y: usize,
+    dst_uv: &mut [u8],
+    dst_stride_uv: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+) -> Result<(), TransposeError> {
+    let chroma_width = width.div_ceil(2);
+    let chroma_height = height.div_ceil(2);
+    let (dst_width, dst_height) = orientation.oriented_size(width, height);
+    let (dst_chroma_width, dst_chroma_height) =
+        orientation.oriented_size(chroma_width, chroma_height);
+
+    check_plane(src_y.len(), src_stride_y, width, height)?;
+    check_plane(src_uv.len(), src_stride_uv, chroma_width * 2, chroma_height)?;
+    check_plane(dst_y.len(), dst_stride_y, dst_width, dst_height)?;
+    check_plane(
+        dst_uv.len(),
+        dst_stride_uv,
+        dst_chroma_width * 2,
+        dst_chroma_height,
+    )?;
+
+    orient_plane8(
+        src_y,
+        src_stride_y,
+        dst_y,
+        dst_stride_y,
+        width,
+        height,
+        orientation,
+    )?;
+    orient_cbcr8(
+        src_uv,
+        src_stride_uv,
+        dst_uv,
+        dst_stride_uv,
+        chroma_width,
+        chroma_height,
+        orientation,
+    )
+}
+
+/// Rotates or mirrors semi-planar YUV 4:2:0 (NV21) frame
+///
+/// Same as [rotate_nv12], chroma plane is interleaved in VU order.
+///
+/// # Arguments
+///
+/// * `src_y`: Source luma plane
+/// * `src_stride_y`: Source luma plane stride
+/// * `src_vu`: Source interleaved VU plane
+/// * `src_stride_vu`: Source VU plane stride
+/// * `dst_y`: Destination luma plane
+/// * `dst_stride_y`: Destination luma plane stride
+/// * `dst_vu`: Destination interleaved VU plane
+/// * `dst_stride_vu`: Destination VU plane stride
+/// * `width`: Source frame width
+/// * `height`: Source frame height
+/// * `orientation`: see [Orientation]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate_nv21(
+    src_y: &[u8],
+    src_stride_y: usize,
+    src_vu: &[u8],
+    src_stride_vu: usize,
+    dst_y: &mut [u8],
+    dst_stride_y: usize,
+    dst_vu: &mut [u8],
+    dst_stride_vu: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+) -> Result<(), TransposeError> {
+    rotate_nv12(
+        src_y,
+        src_stride_y,
+        src_vu,
+        src_stride_vu,
+        dst_y,
+        dst_stride_y,
+        dst_vu,
+        dst_stride_vu,
+        width,
+        height,
+        orientation,
+    )
+}
+
+/// Oriented chroma is split through a scratch band of this size, while it is in cache.
+const BAND_BYTES: usize = 64 * 1024;
+
+/// Orients interleaved chroma plane into destination rows starting from `start_row`,
+/// as many as `output` holds.
+fn orient_cbcr8_rows(
+    input: &[u8],
+    input_stride: usize,
+    output: &mut [u8],
+    output_stride: usize,
+    width: usize,
+    height: usize,
+    start_row: usize,
+    orientation: Orientation,
+) -> Result<(), TransposeError> {
+    let rows = output.len() / output_stride;
+    let (flip_mode, flop_mode) = match orientation {
+        Orientation::Rotate90 => (FlipMode::Flip, FlopMode::Flop),
+        Orientation::Rotate270 => (FlipMode::NoFlip, FlopMode::NoFlop),
+        Orientation::Transpose => (FlipMode::NoFlip, FlopMode::Flop),
+        Orientation::Transverse => (FlipMode::Flip, FlopMode::NoFlop),
+        Orientation::Identity | Orientation::Flip | Orientation::Flop | Orientation::Rotate180 => {
+            // Rows stay rows, destination rows are read from a band of source rows.
+            let first_row = match orientation {
+                Orientation::Flop | Orientation::Rotate180 => height - start_row - rows,
+                _ => start_row,
+            };
+            return orient_cbcr8(
+                &input[first_row * input_stride..(first_row + rows) * input_stride],
+                input_stride,
+                output,
+                output_stride,
+                width,
+                rows,
+                orientation,
+            );
+        }
+    };
+    TransposeTile::new(output, output_stride, start_row, width, height).transpose_plane_with_alpha(
+        input,
+        input_stride,
+        flip_mode,
+        flop_mode,
+    )
+}
+
+/// Rotates or mirrors NV12 frame into planar I420 frame
+///
+/// Chroma is oriented as interleaved pairs in bands through a small scratch,
+/// each band is split into U and V planes right away.
+/// Destination frame has dimensions returned by [Orientation::oriented_size].
+///
+/// # Arguments
+///
+/// * `src_y`: Source luma plane
+/// * `src_stride_y`: Source luma plane stride
+/// * `src_uv`: Source interleaved UV plane
+/// * `src_stride_uv`: Source UV plane stride
+/// * `dst_y`: Destination luma plane
+/// * `dst_stride_y`: Destination luma plane stride
+/// * `dst_u`: Destination U plane
+/// * `dst_stride_u`: Destination U plane stride
+/// * `dst_v`: Destination V plane
+/// * `dst_stride_v`: Destination V plane stride
+/// * `width`: Source frame width
+/// * `height`: Source frame height
+/// * `orientation`: see [Orientation]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate_nv12_to_i420(
+    src_y: &[u8],
+    src_stride_y: usize,
+    src_uv: &[u8],
+    src_stride_uv: usize,
+    dst_y: &mut [u8],
+    dst_stride_y: usize,
+    dst_u: &mut [u8],
+    dst_stride_u: usize,
+    dst_v: &mut [u8],
+    dst_stride_v: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+) -> Result<(), TransposeError> {
+    let chroma_width = width.div_ceil(2);
+    let chroma_height = height.div_ceil(2);
+    let (dst_width, dst_height) = orientation.oriented_size(width, height);
+    let (dst_chroma_width, dst_chroma_height) =
+        orientation.oriented_size(chroma_width, chroma_height);
+
+    check_plane(src_y.len(), src_stride_y, width, height)?;
+    check_plane(src_uv.len(), src_stride_uv, chroma_width * 2, chroma_height)?;
+    check_plane(dst_y.len(), dst_stride_y, dst_width, dst_height)?;
+    check_plane(
+        dst_u.len(),
+        dst_stride_u,
+        dst_chroma_width,
+        dst_chroma_height,
+    )?;
+    check_plane(
+        dst_v.len(),
+        dst_stride_v,
+        dst_chroma_width,
+        dst_chroma_height,
+    )?;
+
+    orient_plane8(
+        src_y,
+        src_stride_y,
+        dst_y,
+        dst_stride_y,
+        width,
+        height,
+        orientation,
+    )?;
+
+    if dst_chroma_width == 0 {
+        return Ok(());
+    }
+
+    let uv_stride = dst_chroma_width * 2;
+    let band_rows = (BAND_BYTES / uv_stride).clamp(1, dst_chroma_height.max(1));
+    let mut scratch = vec![0u8; band_rows * uv_stride];
+
+    for (band, (u, v)) in dst_u
+        .chunks_mut(dst_stride_u * band_rows)
+        .zip(dst_v.chunks_mut(dst_stride_v * band_rows))
+        .enumerate()
+    {
+        let rows = u.len() / dst_stride_u;
+        let scratch = &mut scratch[..rows * uv_stride];
+        orient_cbcr8_rows(
+            src_uv,
+            src_stride_uv,
+            scratch,
+            uv_stride,
+            chroma_width,
+            chroma_height,
+            band * band_rows,
+            orientation,
+        )?;
+        for ((uv, u), v) in scratch
+            .chunks_exact(uv_stride)
+            .zip(u.chunks_exact_mut(dst_stride_u))
+            .zip(v.chunks_exact_mut(dst_stride_v))
+        {
+            for ((src, u), v) in uv
+                .chunks_exact(2)
+                .zip(u[..dst_chroma_width].iter_mut())
+                .zip(v[..dst_chroma_width].iter_mut())
+            {
+                *u = src[0];
+                *v = src[1];
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Rotates or mirrors NV21 frame into planar I420 frame
+///
+/// Same as [rotate_nv12_to_i420], chroma plane is interleaved in VU order.
+///
+/// # Arguments
+///
+/// * `src_y`: Source luma plane
+/// * `src_stride_y`: Source luma plane stride
+/// * `src_vu`: Source interleaved VU plane
+/// * `src_stride_vu`: Source VU plane stride
+/// * `dst_y`: Destination luma plane
+/// * `dst_stride_y`: Destination luma plane stride
+/// * `dst_u`: Destination U plane
+/// * `dst_stride_u`: Destination U plane stride
+/// * `dst_v`: Destination V plane
+/// * `dst_stride_v`: Destination V plane stride
+/// * `width`: Source frame width
+/// * `height`: Source frame height
+/// * `orientation`: see [Orientation]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate_nv21_to_i420(
+    src_y: &[u8],
+    src_stride_y: usize,
+    src_vu: &[u8],
+    src_stride_vu: usize,
+    dst_y: &mut [u8],
+    dst_stride_y: usize,
+    dst_u: &mut [u8],
+    dst_stride_u: usize,
+    dst_v: &mut [u8],
+    dst_stride_v: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+) -> Result<(), TransposeError> {
+    rotate_nv12_to_i420(
+        src_y,
+        src_stride_y,
+        src_vu,
+        src_stride_vu,
+        dst_y,
+        dst_stride_y,
+        dst_v,
+        dst_stride_v,
+        dst_u,
+        dst_stride_u,
+        width,
+        height,
+        orientation,
+    )
+}
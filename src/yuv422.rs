@@ -0,0 +1,220 @@
+/*
+ * // Copyright (c) Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Redistribution and use in source and binary forms, with or without modification,
+ * // are permitted provided that the following conditions are met:
+ * //
+ * // 1.  Redistributions of source code must retain the above copyright notice, this
+ * // list of conditions and the following disclaimer.
+ * //
+ * // 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * // this list of conditions and the following disclaimer in the documentation
+ * // and/or other materials provided with the distribution.
+ * //
+ * // 3.  Neither the name of the copyright holder nor the names of its
+ * // contributors may be used to endorse or promote products derived from
+ * // this software without specific prior written permission.
+ * //
+ * // THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * // AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * // IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * // DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * // FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * // DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * // SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * // CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * // OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * // OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::orientation::{check_plane, orient_plane8};
+use crate::{Orientation, TransposeError};
+use alloc::vec;
+
+/// Declares chroma layout of 4:2:2 frame after rotation that swaps dimensions
+#[derive(Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Debug, Default)]
+pub enum Rotated422Layout {
+    /// Chroma is rotated as is, output is subsampled vertically (4:4:0)
+    #[default]
+    I440,
+    /// Chroma is resampled with 2-tap filter, output is subsampled horizontally (4:2:2)
+    I422,
+}
+
+impl Rotated422Layout {
+    /// Returns dimensions of destination chroma planes for source frame `width` x `height`
+    pub fn chroma_size(
+        self,
+        width: usize,
+        height: usize,
+        orientation: Orientation,
+    ) -> (usize, usize) {
+        if orientation.swaps_dimensions() && self == Rotated422Layout::I422 {
+            (height.div_ceil(2), width)
+        } else {
+            orientation.oriented_size(width.div_ceil(2), height)
+        }
+    }
+}
+
+/// Converts 4:4:0 chroma plane into 4:2:2 chroma plane.
+///
+/// Columns are averaged in pairs, rows are interpolated with 3:1 weights
+/// from the two nearest source rows.
+fn resample_440_to_422(
+    src: &[u8],
+    src_stride: usize,
+    src_width: usize,
+    src_height: usize,
+    dst: &mut [u8],
+    dst_stride: usize,
+    dst_width: usize,
+) {
+    for (y, dst) in dst.chunks_exact_mut(dst_stride).enumerate() {
+        let near = y / 2;
+        let far = if y & 1 == 0 {
+            near.saturating_sub(1)
+        } else {
+            (near + 1).min(src_height - 1)
+        };
+        let near_row = &src[near * src_stride..near * src_stride + src_width];
+        let far_row = &src[far * src_stride..far * src_stride + src_width];
+        for (x, dst) in dst[..dst_width].iter_mut().enumerate() {
+            let x0 = x * 2;
+            let x1 = (x0 + 1).min(src_width - 1);
+            let near_sum = near_row[x0] as u16 + near_row[x1] as u16;
+            let far_sum = far_row[x0] as u16 + far_row[x1] as u16;
+            *dst = ((near_sum * 3 + far_sum + 4) >> 3) as u8;
+        }
+    }
+}
+
+/// Rotates or mirrors planar YUV 4:2:2 (I422) frame
+///
+/// Source chroma planes have `(width + 1) / 2` x `height` samples.
+/// Destination frame has dimensions returned by [Orientation::oriented_size],
+/// destination chroma planes have dimensions returned by [Rotated422Layout::chroma_size].
+/// Layout matters only if orientation swaps dimensions, otherwise output is always I422.
+///
+/// # Arguments
+///
+/// * `src_y`: Source luma plane
+/// * `src_stride_y`: Source luma plane stride
+/// * `src_u`: Source U plane
+/// * `src_stride_u`: Source U plane stride
+/// * `src_v`: Source V plane
+/// * `src_stride_v`: Source V plane stride
+/// * `dst_y`: Destination luma plane
+/// * `dst_stride_y`: Destination luma plane stride
+/// * `dst_u`: Destination U plane
+/// * `dst_stride_u`: Destination U plane stride
+/// * `dst_v`: Destination V plane
+/// * `dst_stride_v`: Destination V plane stride
+/// * `width`: Source frame width
+/// * `height`: Source frame height
+/// * `orientation`: see [Orientation]
+/// * `layout`: see [Rotated422Layout]
+///
+/// returns: Result<(), TransposeError>
+///
+pub fn rotate_i422(
+    src_y: &[u8],
+    src_stride_y: usize,
+    src_u: &[u8],
+    src_stride_u: usize,
+    src_v: &[u8],
+    src_stride_v: usize,
+    dst_y: &mut [u8],
+    dst_stride_y: usize,
+    dst_u: &mut [u8],
+    dst_stride_u: usize,
+    dst_v: &mut [u8],
+    dst_stride_v: usize,
+    width: usize,
+    height: usize,
+    orientation: Orientation,
+    layout: Rotated422Layout,
+) -> Result<(), TransposeError> {
+    let chroma_width = width.div_ceil(2);
+    let (dst_width, dst_height) = orientation.oriented_size(width, height);
+    let (dst_chroma_width, dst_chroma_height) = layout.chroma_size(width, height, orientation);
+
+    check_plane(src_y.len(), src_stride_y, width, height)?;
+    check_plane(src_u.len(), src_stride_u, chroma_width, height)?;
+    check_plane(src_v.len(), src_stride_v, chroma_width, height)?;
+    check_plane(dst_y.len(), dst_stride_y, dst_width, dst_height)?;
+    check_plane(
+        dst_u.len(),
+        dst_stride_u,
+        dst_chroma_width,
+        dst_chroma_height,
+    )?;
+    check_plane(
+        dst_v.len(),
+        dst_stride_v,
+        dst_chroma_width,
+        dst_chroma_height,
+    )?;
+
+    orient_plane8(
+        src_y,
+        src_stride_y,
+        dst_y,
+        dst_stride_y,
+        width,
+        height,
+        orientation,
+    )?;
+
+    if !orientation.swaps_dimensions() || layout == Rotated422Layout::I440 {
+        orient_plane8(
+            src_u,
+            src_stride_u,
+            dst_u,
+            dst_stride_u,
+            chroma_width,
+            height,
+            orientation,
+        )?;
+        return orient_plane8(
+            src_v,
+            src_stride_v,
+            dst_v,
+            dst_stride_v,
+            chroma_width,
+            height,
+            orientation,
+        );
+    }
+
+    if width == 0 || height == 0 {
+        return Ok(());
+    }
+
+    let (rotated_width, rotated_height) = orientation.oriented_size(chroma_width, height);
+    let mut rotated = vec![0u8; rotated_width * rotated_height];
+    for (src, src_stride, dst, dst_stride) in [
+        (src_u, src_stride_u, &mut *dst_u, dst_stride_u),
+        (src_v, src_stride_v, &mut *dst_v, dst_stride_v),
+    ] {
+        orient_plane8(
+            src,
+            src_stride,
+            &mut rotated,
+            rotated_width,
+            chroma_width,
+            height,
+            orientation,
+        )?;
+        resample_440_to_422(
+            &rotated,
+            rotated_width,
+            rotated_width,
+            rotated_height,
+            dst,
+            dst_stride,
+            dst_chroma_width,
+        );
+    }
+    Ok(())
+}